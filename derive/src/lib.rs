@@ -0,0 +1,184 @@
+//! Derive macro for the `yafnv::FnvHash` trait
+//!
+//! Use it through the `derive` feature of [`yafnv`](https://docs.rs/yafnv).
+#![warn(missing_docs, rust_2018_idioms)]
+#![forbid(unsafe_code)]
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, GenericParam, LitInt, Result,
+};
+
+/// Derive `yafnv::FnvHash`
+///
+/// Struct fields are hashed in declaration order.
+/// Enums hash the variant discriminant as `u32` followed by the variant fields.
+/// The discriminant defaults to the index of the variant in declaration order.
+/// It can be set with `#[fnv_hash(discriminant = N)]` to keep hashes stable when variants
+/// are reordered. This does not affect the discriminants of the other variants.
+/// Duplicate discriminants and native `= N` discriminants are rejected.
+#[proc_macro_derive(FnvHash, attributes(fnv_hash))]
+pub fn derive_fnv_hash(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(mut input: DeriveInput) -> Result<TokenStream> {
+    for param in input.generics.params.iter_mut() {
+        if let GenericParam::Type(ty) = param {
+            ty.bounds.push(parse_quote!(::yafnv::FnvHash));
+        }
+    }
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, update) = fields(&data.fields);
+            quote! {
+                let Self #pattern = self;
+                #update
+                state
+            }
+        }
+        Data::Enum(data) => {
+            let mut seen = Vec::new();
+            let mut arms = Vec::new();
+            for (index, variant) in data.variants.iter().enumerate() {
+                if let Some((_, expr)) = &variant.discriminant {
+                    return Err(Error::new_spanned(
+                        expr,
+                        "native discriminants are not supported, use `#[fnv_hash(discriminant = N)]`",
+                    ));
+                }
+                let discriminant = discriminant(&variant.attrs)?.unwrap_or(index as u32);
+                if seen.contains(&discriminant) {
+                    return Err(Error::new_spanned(
+                        variant,
+                        format!("duplicate discriminant {discriminant}"),
+                    ));
+                }
+                seen.push(discriminant);
+                let ident = &variant.ident;
+                let (pattern, update) = fields(&variant.fields);
+                arms.push(quote! {
+                    Self::#ident #pattern => {
+                        let state = ::yafnv::FnvHash::fnv_hash::<__T, __V>(&#discriminant, state);
+                        #update
+                        state
+                    }
+                });
+            }
+            if arms.is_empty() {
+                // `match self {}` on `&Self` is not exhaustive
+                quote!(match *self {})
+            } else {
+                quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "FnvHash can not be derived for unions",
+            ))
+        }
+    };
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::yafnv::FnvHash for #ident #ty_generics #where_clause {
+            #[inline]
+            fn fnv_hash<__T, __V>(&self, state: __T) -> __T
+            where
+                __T: ::yafnv::Fnv,
+                u8: ::yafnv::__private::AsPrimitive<__T>,
+                __V: ::yafnv::Variant,
+            {
+                #body
+            }
+        }
+    })
+}
+
+/// Destructuring pattern and state update for the fields
+fn fields(fields: &Fields) -> (TokenStream, TokenStream) {
+    let names: Vec<_> = (0..fields.len())
+        .map(|i| format_ident!("__field{}", i))
+        .collect();
+    let pattern = match fields {
+        Fields::Named(fields) => {
+            let idents = fields.named.iter().map(|f| &f.ident);
+            quote!({ #(#idents: #names),* })
+        }
+        Fields::Unnamed(_) => quote!(( #(#names),* )),
+        Fields::Unit => quote!(),
+    };
+    let update = quote! {
+        #(let state = ::yafnv::FnvHash::fnv_hash::<__T, __V>(#names, state);)*
+    };
+    (pattern, update)
+}
+
+/// Parse `#[fnv_hash(discriminant = N)]`
+fn discriminant(attrs: &[syn::Attribute]) -> Result<Option<u32>> {
+    let mut discriminant = None;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("fnv_hash")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("discriminant") {
+                let lit: LitInt = meta.value()?.parse()?;
+                discriminant = Some(lit.base10_parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported fnv_hash attribute"))
+            }
+        })?;
+    }
+    Ok(discriminant)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn error(input: DeriveInput) -> String {
+        expand(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn discriminants() {
+        assert!(expand(parse_quote!(
+            enum E {
+                A,
+                #[fnv_hash(discriminant = 5)]
+                B,
+                C,
+            }
+        ))
+        .is_ok());
+        assert_eq!(
+            error(parse_quote!(
+                enum E {
+                    A,
+                    #[fnv_hash(discriminant = 0)]
+                    B,
+                }
+            )),
+            "duplicate discriminant 0"
+        );
+        assert!(error(parse_quote!(
+            enum E {
+                A = 1,
+            }
+        ))
+        .starts_with("native discriminants"));
+        assert!(expand(parse_quote!(
+            enum E {}
+        ))
+        .is_ok());
+    }
+}
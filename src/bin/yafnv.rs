@@ -0,0 +1,626 @@
+//! `yafnv` hashsum tool
+//!
+//! Compute FNV hashes of files, standard input, or strings.
+//! The output format is compatible with `sha256sum` and friends.
+//! File names need not be UTF-8: they are written as is to checksum lines
+//! and with `\xNN` escapes to diagnostics.
+
+use std::borrow::Cow;
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use memmap2::Mmap;
+use num_traits::AsPrimitive;
+use yafnv::Fnv;
+
+const USAGE: &str = "\
+Usage: yafnv [OPTIONS] [FILE]...
+
+Print or check Fowler-Noll-Vo hashes.
+With no FILE, or when FILE is -, read standard input.
+
+Options:
+  -a, --variant <0|1|1a>     FNV variant [default: 1a]
+  -w, --width <32|64|128>    Hash width in bits [default: 64]
+  -f, --format <FORMAT>      Output format: hex, dec, or raw [default: hex]
+  -s, --string <STRING>      Hash STRING instead of files (repeatable)
+  -c, --check                Read hashes from the FILEs and check them.
+                             Hex and dec hashes are detected, --format is ignored
+  -j, --jobs <N>             Number of files to hash in parallel [default: available cores]
+  -h, --help                 Print help
+";
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Variant {
+    Fnv0,
+    Fnv1,
+    Fnv1a,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Format {
+    Hex,
+    Dec,
+    Raw,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Algorithm {
+    variant: Variant,
+    bits: u32,
+}
+
+impl Algorithm {
+    fn init(&self) -> u128 {
+        match (self.variant, self.bits) {
+            (Variant::Fnv0, _) => 0,
+            (_, 32) => u32::OFFSET_BASIS as _,
+            (_, 64) => u64::OFFSET_BASIS as _,
+            _ => u128::OFFSET_BASIS,
+        }
+    }
+
+    fn update(&self, state: u128, data: &[u8]) -> u128 {
+        fn update<T>(variant: Variant, state: T, data: &[u8]) -> T
+        where
+            T: Fnv,
+            u8: AsPrimitive<T>,
+        {
+            let data = data.iter().copied();
+            match variant {
+                Variant::Fnv0 | Variant::Fnv1 => state.fnv1(data),
+                Variant::Fnv1a => state.fnv1a(data),
+            }
+        }
+
+        match self.bits {
+            32 => update(self.variant, state as u32, data) as _,
+            64 => update(self.variant, state as u64, data) as _,
+            _ => update(self.variant, state, data),
+        }
+    }
+
+    fn hash(&self, data: &[u8]) -> u128 {
+        self.update(self.init(), data)
+    }
+
+    fn hash_reader<R: Read>(&self, mut reader: R) -> io::Result<u128> {
+        let mut buf = vec![0; 1 << 16];
+        let mut state = self.init();
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) => return Ok(state),
+                Ok(len) => state = self.update(state, &buf[..len]),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn hash_file(&self, path: &Path) -> io::Result<u128> {
+        if path.as_os_str() == "-" {
+            return self.hash_reader(io::stdin().lock());
+        }
+        let file = File::open(path)?;
+        let meta = file.metadata()?;
+        if meta.is_file() && meta.len() > 0 {
+            // SAFETY: The mapping is only read and dropped before returning.
+            // As with all memory-mapped reads, truncation of the file by another process
+            // while hashing may terminate the process.
+            let map = unsafe { Mmap::map(&file)? };
+            Ok(self.hash(&map))
+        } else {
+            self.hash_reader(file)
+        }
+    }
+
+    /// Hash files in parallel, returning the results in order.
+    fn hash_files(&self, paths: &[PathBuf], jobs: usize) -> Vec<io::Result<u128>> {
+        let next = AtomicUsize::new(0);
+        let results: Vec<_> = paths.iter().map(|_| Mutex::new(None)).collect();
+        thread::scope(|s| {
+            for _ in 0..jobs.clamp(1, paths.len().max(1)) {
+                s.spawn(|| loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(path) = paths.get(i) else {
+                        break;
+                    };
+                    *results[i].lock().unwrap() = Some(self.hash_file(path));
+                });
+            }
+        });
+        results
+            .into_iter()
+            .map(|r| r.into_inner().unwrap().unwrap())
+            .collect()
+    }
+
+    fn format(&self, hash: u128, format: Format) -> Vec<u8> {
+        match format {
+            Format::Hex => format!("{:01$x}", hash, self.bits as usize / 4).into_bytes(),
+            Format::Dec => hash.to_string().into_bytes(),
+            Format::Raw => hash.to_be_bytes()[16 - self.bits as usize / 8..].to_vec(),
+        }
+    }
+
+    /// Parse a hash in hex or dec format.
+    ///
+    /// Hex hashes have exactly `bits / 4` digits. A hash of that many decimal digits
+    /// is ambiguous and both readings are returned.
+    fn parse(&self, hash: &str) -> [Option<u128>; 2] {
+        let fits = |h: &u128| h.checked_shr(self.bits).unwrap_or(0) == 0;
+        let hex = (hash.len() == self.bits as usize / 4
+            && hash.bytes().all(|c| c.is_ascii_hexdigit()))
+        .then(|| u128::from_str_radix(hash, 16).ok())
+        .flatten();
+        let dec = (!hash.is_empty() && hash.bytes().all(|c| c.is_ascii_digit()))
+            .then(|| hash.parse().ok().filter(fits))
+            .flatten();
+        [hex, dec]
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Options {
+    algorithm: Algorithm,
+    format: Format,
+    check: bool,
+    jobs: usize,
+    strings: Vec<OsString>,
+    files: Vec<PathBuf>,
+}
+
+/// An option value that must be UTF-8
+fn utf8(value: OsString) -> Result<String, String> {
+    value
+        .into_string()
+        .map_err(|v| format!("invalid value: {}", quote(v.as_encoded_bytes())))
+}
+
+impl Options {
+    fn parse<I: IntoIterator<Item = OsString>>(args: I) -> Result<Option<Self>, String> {
+        let mut opts = Options {
+            algorithm: Algorithm {
+                variant: Variant::Fnv1a,
+                bits: 64,
+            },
+            format: Format::Hex,
+            check: false,
+            jobs: thread::available_parallelism().map_or(1, |n| n.get()),
+            strings: Vec::new(),
+            files: Vec::new(),
+        };
+        let mut args = args.into_iter();
+        let mut only_files = false;
+        while let Some(arg) = args.next() {
+            let bytes = arg.as_encoded_bytes();
+            if only_files || bytes == b"-" || !bytes.starts_with(b"-") {
+                opts.files.push(arg.into());
+                continue;
+            }
+            let arg = arg
+                .into_string()
+                .map_err(|a| format!("unknown option: {}", quote(a.as_encoded_bytes())))?;
+            let (name, inline) = match arg.split_once('=') {
+                Some((name, value)) if arg.starts_with("--") => (name, Some(value.into())),
+                _ => (arg.as_str(), None),
+            };
+            let mut value = || {
+                inline
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("missing value for {name}"))
+            };
+            match name {
+                "--" => only_files = true,
+                "-h" | "--help" => return Ok(None),
+                "-c" | "--check" => opts.check = true,
+                "-a" | "--variant" => {
+                    opts.algorithm.variant = match utf8(value()?)?.as_str() {
+                        "0" => Variant::Fnv0,
+                        "1" => Variant::Fnv1,
+                        "1a" => Variant::Fnv1a,
+                        v => return Err(format!("invalid variant: {v}")),
+                    }
+                }
+                "-w" | "--width" => {
+                    opts.algorithm.bits = match utf8(value()?)?.as_str() {
+                        "32" => 32,
+                        "64" => 64,
+                        "128" => 128,
+                        w => return Err(format!("invalid width: {w}")),
+                    }
+                }
+                "-f" | "--format" => {
+                    opts.format = match utf8(value()?)?.as_str() {
+                        "hex" => Format::Hex,
+                        "dec" => Format::Dec,
+                        "raw" => Format::Raw,
+                        f => return Err(format!("invalid format: {f}")),
+                    }
+                }
+                "-s" | "--string" => opts.strings.push(value()?),
+                "-j" | "--jobs" => {
+                    let jobs = utf8(value()?)?;
+                    opts.jobs = jobs
+                        .parse()
+                        .map_err(|_| format!("invalid number of jobs: {jobs}"))?;
+                }
+                _ => return Err(format!("unknown option: {arg}")),
+            }
+        }
+        if opts.check && !opts.strings.is_empty() {
+            return Err("--check requires files".into());
+        }
+        if opts.files.is_empty() && opts.strings.is_empty() {
+            opts.files.push("-".into());
+        }
+        Ok(Some(opts))
+    }
+}
+
+/// The bytes of a file name
+fn name(path: &Path) -> &[u8] {
+    path.as_os_str().as_encoded_bytes()
+}
+
+/// A file name from the bytes of a checksum line
+#[cfg(unix)]
+fn from_name(name: Vec<u8>) -> Option<PathBuf> {
+    use std::os::unix::ffi::OsStringExt;
+    Some(OsString::from_vec(name).into())
+}
+
+/// A file name from the bytes of a checksum line
+#[cfg(not(unix))]
+fn from_name(name: Vec<u8>) -> Option<PathBuf> {
+    String::from_utf8(name).ok().map(Into::into)
+}
+
+/// A name for diagnostics, with non-UTF-8 names escaped as `\xNN`
+fn quote(name: &[u8]) -> Cow<'_, str> {
+    match std::str::from_utf8(name) {
+        Ok(name) => name.into(),
+        Err(_) => name.escape_ascii().to_string().into(),
+    }
+}
+
+/// Escape a file name like `sha256sum`
+///
+/// Names containing `\`, newline, or carriage return are escaped and the line
+/// is prefixed with `\`. Returns the prefix and the escaped name.
+fn escape(name: &[u8]) -> (&'static str, Cow<'_, [u8]>) {
+    if name.iter().any(|c| b"\\\n\r".contains(c)) {
+        let mut out = Vec::with_capacity(name.len() + 2);
+        for &c in name {
+            match c {
+                b'\\' => out.extend_from_slice(b"\\\\"),
+                b'\n' => out.extend_from_slice(b"\\n"),
+                b'\r' => out.extend_from_slice(b"\\r"),
+                c => out.push(c),
+            }
+        }
+        ("\\", out.into())
+    } else {
+        ("", name.into())
+    }
+}
+
+/// Undo [`escape()`] on a name
+fn unescape(name: &[u8]) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(name.len());
+    let mut bytes = name.iter();
+    while let Some(&c) = bytes.next() {
+        out.push(match c {
+            b'\\' => match bytes.next()? {
+                b'\\' => b'\\',
+                b'n' => b'\n',
+                b'r' => b'\r',
+                _ => return None,
+            },
+            c => c,
+        });
+    }
+    Some(out)
+}
+
+/// Parse a checksum line: `<hash>  <name>` or `<hash> *<name>`,
+/// optionally prefixed with `\` for an escaped name.
+fn parse_line(line: &[u8]) -> Option<(&str, Cow<'_, [u8]>)> {
+    let (escaped, line) = match line.strip_prefix(b"\\") {
+        Some(line) => (true, line),
+        None => (false, line),
+    };
+    let sep = line.iter().position(|&c| c == b' ')?;
+    let (hash, name) = (&line[..sep], &line[sep + 1..]);
+    let name = name
+        .strip_prefix(b" ")
+        .or_else(|| name.strip_prefix(b"*"))?;
+    let hash = std::str::from_utf8(hash).ok()?;
+    if hash.is_empty() || name.is_empty() {
+        return None;
+    }
+    let name = if escaped {
+        unescape(name)?.into()
+    } else {
+        name.into()
+    };
+    Some((hash, name))
+}
+
+/// Write a check result line.
+fn status(out: &mut impl Write, name: &[u8], status: &str) -> io::Result<()> {
+    let (prefix, name) = escape(name);
+    out.write_all(prefix.as_bytes())?;
+    out.write_all(&name)?;
+    writeln!(out, ": {status}")
+}
+
+fn check(opts: &Options) -> io::Result<bool> {
+    let mut stdout = io::stdout().lock();
+    let mut expected = Vec::new();
+    let mut names = Vec::new();
+    let mut malformed = 0;
+    let mut unreadable = 0;
+    for path in opts.files.iter() {
+        let reader: Box<dyn BufRead> = if path.as_os_str() == "-" {
+            Box::new(io::stdin().lock())
+        } else {
+            match File::open(path) {
+                Ok(file) => Box::new(BufReader::new(file)),
+                Err(e) => {
+                    eprintln!("yafnv: {}: {e}", quote(name(path)));
+                    status(&mut stdout, name(path), "FAILED open or read")?;
+                    unreadable += 1;
+                    continue;
+                }
+            }
+        };
+        let mut found = 0;
+        for line in reader.split(b'\n') {
+            let mut line = match line {
+                Ok(line) => line,
+                Err(e) => {
+                    eprintln!("yafnv: {}: {e}", quote(name(path)));
+                    status(&mut stdout, name(path), "FAILED open or read")?;
+                    unreadable += 1;
+                    break;
+                }
+            };
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+            let parsed = parse_line(&line).and_then(|(hash, file)| {
+                let hash = opts.algorithm.parse(hash);
+                let file = from_name(file.into_owned())?;
+                hash.iter().any(Option::is_some).then_some((hash, file))
+            });
+            if let Some((hash, file)) = parsed {
+                expected.push(hash);
+                names.push(file);
+                found += 1;
+            } else if !line.trim_ascii().is_empty() {
+                malformed += 1;
+            }
+        }
+        if found == 0 {
+            eprintln!(
+                "yafnv: {}: no properly formatted checksum lines found",
+                quote(name(path))
+            );
+        }
+    }
+    let mut failed = 0;
+    let results = opts.algorithm.hash_files(&names, opts.jobs);
+    for ((path, expected), result) in names.iter().zip(expected).zip(results) {
+        match result {
+            Ok(hash) if expected.contains(&Some(hash)) => status(&mut stdout, name(path), "OK")?,
+            Ok(_) => {
+                status(&mut stdout, name(path), "FAILED")?;
+                failed += 1;
+            }
+            Err(e) => {
+                eprintln!("yafnv: {}: {e}", quote(name(path)));
+                status(&mut stdout, name(path), "FAILED open or read")?;
+                unreadable += 1;
+            }
+        }
+    }
+    if malformed > 0 {
+        eprintln!("yafnv: WARNING: {malformed} lines are improperly formatted");
+    }
+    if unreadable > 0 {
+        eprintln!("yafnv: WARNING: {unreadable} listed files could not be read");
+    }
+    if failed > 0 {
+        eprintln!("yafnv: WARNING: {failed} computed checksums did NOT match");
+    }
+    Ok(failed == 0 && unreadable == 0 && !names.is_empty())
+}
+
+fn hash(opts: &Options) -> io::Result<bool> {
+    let mut stdout = io::stdout().lock();
+    let mut ok = true;
+    let strings = opts.strings.iter().map(|s| {
+        let s = s.as_encoded_bytes();
+        ([&b"\""[..], s, b"\""].concat(), Ok(opts.algorithm.hash(s)))
+    });
+    let files = opts
+        .files
+        .iter()
+        .map(|path| name(path).to_vec())
+        .zip(opts.algorithm.hash_files(&opts.files, opts.jobs));
+    for (name, result) in strings.chain(files) {
+        match result {
+            Ok(hash) => {
+                let hash = opts.algorithm.format(hash, opts.format);
+                if opts.format == Format::Raw {
+                    stdout.write_all(&hash)?;
+                } else {
+                    let (prefix, name) = escape(&name);
+                    stdout.write_all(prefix.as_bytes())?;
+                    stdout.write_all(&hash)?;
+                    stdout.write_all(b"  ")?;
+                    stdout.write_all(&name)?;
+                    stdout.write_all(b"\n")?;
+                }
+            }
+            Err(e) => {
+                eprintln!("yafnv: {}: {e}", quote(&name));
+                ok = false;
+            }
+        }
+    }
+    stdout.flush()?;
+    Ok(ok)
+}
+
+fn main() -> ExitCode {
+    let opts = match Options::parse(std::env::args_os().skip(1)) {
+        Ok(Some(opts)) => opts,
+        Ok(None) => {
+            print!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("yafnv: {e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    let result = if opts.check {
+        check(&opts)
+    } else {
+        hash(&opts)
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("yafnv: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Option<Options>, String> {
+        Options::parse(args.iter().map(OsString::from))
+    }
+
+    #[test]
+    fn algorithm() {
+        for (variant, bits, hash) in [
+            (Variant::Fnv0, 32, yafnv::fnv0::<u32>(b"foobar") as u128),
+            (Variant::Fnv1, 64, yafnv::fnv1::<u64>(b"foobar") as u128),
+            (Variant::Fnv1a, 128, yafnv::fnv1a::<u128>(b"foobar")),
+        ] {
+            let algo = Algorithm { variant, bits };
+            assert_eq!(algo.hash(b"foobar"), hash);
+            assert_eq!(algo.hash_reader(&b"foobar"[..]).unwrap(), hash);
+        }
+        let algo = Algorithm {
+            variant: Variant::Fnv1a,
+            bits: 32,
+        };
+        assert_eq!(algo.format(0xbf9cf968, Format::Hex), b"bf9cf968");
+        assert_eq!(algo.format(0x1, Format::Hex), b"00000001");
+        assert_eq!(algo.format(0xbf9cf968, Format::Dec), b"3214735720");
+        assert_eq!(
+            algo.format(0xbf9cf968, Format::Raw),
+            [0xbf, 0x9c, 0xf9, 0x68]
+        );
+    }
+
+    #[test]
+    fn parse_hash() {
+        let algo = Algorithm {
+            variant: Variant::Fnv1a,
+            bits: 32,
+        };
+        assert_eq!(algo.parse("bf9cf968"), [Some(0xbf9cf968), None]);
+        assert_eq!(algo.parse("3214735720"), [None, Some(0xbf9cf968)]);
+        assert_eq!(algo.parse("00000001"), [Some(1), Some(1)]);
+        assert_eq!(algo.parse("12345678"), [Some(0x12345678), Some(12345678)]);
+        assert_eq!(algo.parse("1"), [None, Some(1)]);
+        assert_eq!(algo.parse("4294967296"), [None, None]);
+        assert_eq!(algo.parse("+1"), [None, None]);
+        assert_eq!(algo.parse(""), [None, None]);
+        let algo = Algorithm {
+            variant: Variant::Fnv1a,
+            bits: 128,
+        };
+        assert_eq!(algo.parse(&u128::MAX.to_string()), [None, Some(u128::MAX)]);
+    }
+
+    #[test]
+    fn options() {
+        let opts = parse(&["-w", "32", "--variant=1", "-s", "foo", "a", "--", "-b"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(opts.algorithm.bits, 32);
+        assert_eq!(opts.algorithm.variant, Variant::Fnv1);
+        assert_eq!(opts.strings, ["foo"]);
+        assert_eq!(opts.files, [Path::new("a"), Path::new("-b")]);
+        assert_eq!(parse(&[]).unwrap().unwrap().files, [Path::new("-")]);
+        assert_eq!(parse(&["--help"]), Ok(None));
+        assert!(parse(&["-w", "16"]).is_err());
+        assert!(parse(&["-w"]).is_err());
+        assert!(parse(&["-c", "-s", "foo"]).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let bad = OsString::from_vec(b"bad\xff".to_vec());
+        let opts = Options::parse([bad.clone()]).unwrap().unwrap();
+        assert_eq!(opts.files, [PathBuf::from(bad.clone())]);
+        assert_eq!(name(&opts.files[0]), b"bad\xff");
+        assert_eq!(from_name(b"bad\xff".to_vec()), Some(bad.clone().into()));
+        assert_eq!(quote(b"bad\xff"), "bad\\xff");
+        assert_eq!(quote("ü".as_bytes()), "ü");
+        assert!(Options::parse([OsString::from("-s"), bad.clone()]).is_ok());
+        assert!(Options::parse([OsString::from("-w"), bad.clone()]).is_err());
+        let mut opt = b"-".to_vec();
+        opt.extend_from_slice(bad.as_encoded_bytes());
+        assert!(Options::parse([OsString::from_vec(opt)]).is_err());
+    }
+
+    #[test]
+    fn line() {
+        assert_eq!(
+            parse_line(b"abcd  foo bar"),
+            Some(("abcd", b"foo bar"[..].into()))
+        );
+        assert_eq!(parse_line(b"abcd *foo"), Some(("abcd", b"foo"[..].into())));
+        assert_eq!(parse_line(b"abcd foo"), None);
+        assert_eq!(parse_line(b"abcd  "), None);
+        assert_eq!(
+            parse_line(b"\\abcd  a\\nb\\\\c"),
+            Some(("abcd", b"a\nb\\c"[..].into()))
+        );
+        assert_eq!(parse_line(b"\\abcd  a\\x"), None);
+        assert_eq!(
+            parse_line(b"abcd  bad\xff"),
+            Some(("abcd", b"bad\xff"[..].into()))
+        );
+    }
+
+    #[test]
+    fn escaping() {
+        assert_eq!(escape(b"foo"), ("", b"foo"[..].into()));
+        let (prefix, name) = escape(b"a\nb\\c\rd\xff");
+        assert_eq!((prefix, &*name), ("\\", &b"a\\nb\\\\c\\rd\xff"[..]));
+        assert_eq!(unescape(&name).unwrap(), b"a\nb\\c\rd\xff");
+    }
+}
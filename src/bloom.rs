@@ -0,0 +1,203 @@
+//! Fixed size Bloom filter
+//!
+//! [`BloomFilter`] answers "possibly seen before" or "definitely not seen before" for byte
+//! strings without storing them. The `K` probe positions are derived by double hashing
+//! `h1 + i * h2` from the two halves of a 128 bit FNV-1a hash.
+
+use core::borrow::Borrow;
+
+use crate::{mul_shift_64, Fnv};
+
+/// A fixed size Bloom filter
+///
+/// The filter has `BITS` bits and sets `K` of them per item.
+/// `BYTES` is the size of the storage and must be `BITS / 8`: stable Rust can not
+/// compute it from a generic `BITS`. `BITS` must be a non-zero multiple of 8.
+/// Violating this is a compile time error.
+/// Define a type alias with a concrete `BITS` to compute `BYTES` once (see below).
+///
+/// For `n` items the false positive rate is about `(1 - exp(-K n / BITS))^K`.
+/// It is lowest for `K = BITS / n * ln 2`.
+///
+/// ```
+/// use yafnv::BloomFilter;
+///
+/// const BITS: usize = 4096;
+/// // 4 probes: about 2 % false positives after 400 items
+/// type Seen = BloomFilter<BITS, 4, { BITS / 8 }>;
+///
+/// let mut seen = Seen::new();
+/// let fresh = (0u32..400).filter(|id| seen.insert(id.to_le_bytes())).count();
+/// assert!(fresh > 390);
+/// // Duplicates are always detected
+/// assert!(!seen.insert(17u32.to_le_bytes()));
+/// assert!(seen.contains(399u32.to_le_bytes()));
+/// assert!(seen.false_positive_rate() < 0.03);
+/// ```
+///
+/// ```compile_fail
+/// // 4096 bits do not fit into 256 bytes
+/// let seen = yafnv::BloomFilter::<4096, 4, 256>::new();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BloomFilter<const BITS: usize, const K: usize, const BYTES: usize> {
+    bits: [u8; BYTES],
+}
+
+impl<const BITS: usize, const K: usize, const BYTES: usize> Default
+    for BloomFilter<BITS, K, BYTES>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BITS: usize, const K: usize, const BYTES: usize> BloomFilter<BITS, K, BYTES> {
+    /// Create an empty filter.
+    pub const fn new() -> Self {
+        Self::from_bytes([0; BYTES])
+    }
+
+    /// Create a filter from its serialized form.
+    ///
+    /// See [`Self::as_bytes()`].
+    pub const fn from_bytes(bits: [u8; BYTES]) -> Self {
+        const {
+            assert!(BITS > 0, "empty filter");
+            assert!(BITS == 8 * BYTES, "BYTES must be BITS / 8");
+            assert!(K > 0, "no probes");
+        }
+        Self { bits }
+    }
+
+    /// The serialized form of the filter
+    ///
+    /// Bit `i` is bit `i % 8` (least significant first) of byte `i / 8`.
+    /// The format is independent of the platform.
+    pub const fn as_bytes(&self) -> &[u8; BYTES] {
+        &self.bits
+    }
+
+    /// Return the serialized form of the filter.
+    pub const fn into_bytes(self) -> [u8; BYTES] {
+        self.bits
+    }
+
+    /// The probe positions of an item
+    fn probes<I>(data: I) -> impl Iterator<Item = usize>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<u8>,
+    {
+        let h = u128::OFFSET_BASIS.fnv1a(data);
+        // A second pass diffuses the trailing bytes into the high bits
+        let h = h.fnv1a(h.to_le_bytes());
+        // A zero step would probe a single position `K` times
+        let (h1, h2) = ((h >> 64) as u64, h as u64 | 1);
+        (0..K as u64)
+            .map(move |i| mul_shift_64(h1.wrapping_add(i.wrapping_mul(h2)), BITS as _) as _)
+    }
+
+    /// Insert an item.
+    ///
+    /// Returns whether the item was definitely not contained before.
+    pub fn insert<I>(&mut self, data: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: Borrow<u8>,
+    {
+        let mut new = false;
+        for i in Self::probes(data) {
+            let (byte, mask) = (&mut self.bits[i / 8], 1 << (i % 8));
+            new |= *byte & mask == 0;
+            *byte |= mask;
+        }
+        new
+    }
+
+    /// Whether the item is possibly contained
+    ///
+    /// There are no false negatives. False positives occur at about
+    /// [`Self::false_positive_rate()`].
+    pub fn contains<I>(&self, data: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: Borrow<u8>,
+    {
+        Self::probes(data).all(|i| self.bits[i / 8] & (1 << (i % 8)) != 0)
+    }
+
+    /// Add all items of another filter.
+    pub fn union(&mut self, other: &Self) {
+        for (a, b) in self.bits.iter_mut().zip(other.bits) {
+            *a |= b;
+        }
+    }
+
+    /// Remove all items.
+    pub fn clear(&mut self) {
+        self.bits = [0; BYTES];
+    }
+
+    /// Whether no items have been inserted
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|b| *b == 0)
+    }
+
+    /// The number of bits set
+    pub fn count_ones(&self) -> usize {
+        self.bits.iter().map(|b| b.count_ones() as usize).sum()
+    }
+
+    /// Estimate the false positive rate.
+    ///
+    /// This is the probability `(ones / BITS)^K` that all probes of an item
+    /// not inserted hit set bits.
+    pub fn false_positive_rate(&self) -> f32 {
+        let fill = self.count_ones() as f32 / BITS as f32;
+        (0..K).fold(1.0, |p, _| p * fill)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn filter() {
+        let mut f = BloomFilter::<4096, 4, 512>::default();
+        assert!(f.is_empty());
+        assert_eq!(f.false_positive_rate(), 0.0);
+        for id in 0u32..600 {
+            f.insert(id.to_le_bytes());
+        }
+        assert!((0u32..600).all(|id| f.contains(id.to_le_bytes())));
+        let n = 100_000;
+        let fp = (600u32..600 + n)
+            .filter(|id| f.contains(id.to_le_bytes()))
+            .count() as f32
+            / n as f32;
+        // (1 - exp(-4 * 600 / 4096))^4 = 0.039
+        let est = f.false_positive_rate();
+        assert!((0.03..0.05).contains(&est));
+        assert!((fp - est).abs() < 0.2 * est);
+        f.clear();
+        assert!(f.is_empty());
+        assert!(!f.contains(0u32.to_le_bytes()));
+    }
+
+    #[test]
+    fn union_bytes() {
+        let mut a = BloomFilter::<128, 3, 16>::new();
+        let mut b = BloomFilter::<128, 3, 16>::new();
+        assert!(a.insert(b"foo"));
+        assert!(!a.insert(b"foo"));
+        b.insert(b"bar");
+        a.union(&b);
+        assert!(a.contains(b"foo") && a.contains(b"bar"));
+        assert!(a.count_ones() <= 6);
+        let c = BloomFilter::<128, 3, 16>::from_bytes(*a.as_bytes());
+        assert_eq!(c, a);
+        assert_eq!(c.into_bytes(), *a.as_bytes());
+    }
+}
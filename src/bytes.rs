@@ -0,0 +1,100 @@
+//! Byte adapters for wider input symbols
+//!
+//! FNV consumes bytes. These adapters serialize streams of words or characters
+//! into bytes on the fly, without an intermediate buffer.
+
+use num_traits::ToBytes;
+
+/// Hash words (e.g. `u16` or `u32`) in little-endian byte order.
+///
+/// ```
+/// use yafnv::{fnv1a, le_bytes};
+///
+/// let utf16 = "foo".encode_utf16();
+/// assert_eq!(fnv1a::<u32>(le_bytes(utf16)), fnv1a::<u32>(b"f\0o\0o\0"));
+/// ```
+pub fn le_bytes<I>(words: I) -> impl Iterator<Item = u8>
+where
+    I: IntoIterator,
+    I::Item: ToBytes,
+{
+    words.into_iter().flat_map(|word| {
+        let bytes = word.to_le_bytes();
+        (0..bytes.as_ref().len()).map(move |i| bytes.as_ref()[i])
+    })
+}
+
+/// Hash words (e.g. `u16` or `u32`) in big-endian byte order.
+///
+/// ```
+/// use yafnv::{be_bytes, fnv1a};
+///
+/// let regs = [0x1234_5678u32, 0x9abc_def0];
+/// assert_eq!(
+///     fnv1a::<u64>(be_bytes(regs)),
+///     fnv1a::<u64>(&[0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0])
+/// );
+/// ```
+pub fn be_bytes<I>(words: I) -> impl Iterator<Item = u8>
+where
+    I: IntoIterator,
+    I::Item: ToBytes,
+{
+    words.into_iter().flat_map(|word| {
+        let bytes = word.to_be_bytes();
+        (0..bytes.as_ref().len()).map(move |i| bytes.as_ref()[i])
+    })
+}
+
+/// Hash characters in their UTF-8 encoding.
+///
+/// ```
+/// use yafnv::{fnv1a, utf8_bytes};
+///
+/// let chars = ['f', 'ö', 'ö'];
+/// assert_eq!(fnv1a::<u32>(utf8_bytes(chars)), fnv1a::<u32>("föö".as_bytes()));
+/// ```
+pub fn utf8_bytes<I>(chars: I) -> impl Iterator<Item = u8>
+where
+    I: IntoIterator<Item = char>,
+{
+    chars.into_iter().flat_map(|c| {
+        let mut buf = [0; 4];
+        let len = c.encode_utf8(&mut buf).len();
+        buf.into_iter().take(len)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{fnv1, fnv1a, Fnv};
+
+    #[test]
+    fn words() {
+        let data = [0x0102u16, 0x0304];
+        assert!(le_bytes(data).eq([2, 1, 4, 3]));
+        assert!(be_bytes(data).eq([1, 2, 3, 4]));
+        assert!(le_bytes([0x01020304u32]).eq([4, 3, 2, 1]));
+        assert!(be_bytes([-2i64]).eq([0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe]));
+        assert_eq!(fnv1::<u64>(le_bytes(data)), fnv1::<u64>(&[2, 1, 4, 3]));
+    }
+
+    #[test]
+    fn chars() {
+        let s = "a€😀";
+        assert!(utf8_bytes(s.chars()).eq(s.bytes()));
+        assert_eq!(
+            fnv1a::<u128>(utf8_bytes(s.chars())),
+            fnv1a::<u128>(s.as_bytes())
+        );
+    }
+
+    #[test]
+    fn borrow() {
+        let data = b"foobar";
+        assert_eq!(u32::OFFSET_BASIS.fnv1a(data.iter()), 0xbf9cf968);
+        assert_eq!(u32::OFFSET_BASIS.fnv1a(*data), 0xbf9cf968);
+        assert_eq!(fnv1a::<u32>(data.iter().rev().rev()), 0xbf9cf968);
+    }
+}
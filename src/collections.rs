@@ -0,0 +1,158 @@
+//! Map and set aliases for third party collections
+//!
+//! The aliases use [`FnvBuildHasher`](crate::FnvBuildHasher) and are available with the
+//! respective cargo features `hashbrown`, `indexmap`, `dashmap`, and `hash32` (for `heapless`).
+//!
+//! Without the `std` feature, the `hashbrown` aliases are also re-exported as
+//! [`Fnv1aHashMap`](crate::Fnv1aHashMap) and [`Fnv1aHashSet`](crate::Fnv1aHashSet)
+//! so that the same aliases can be used in `std` and `alloc`-only code.
+
+/// [`hashbrown`](https://docs.rs/hashbrown) aliases
+#[cfg(feature = "hashbrown")]
+pub mod hashbrown {
+    use ::hashbrown::{HashMap, HashSet};
+
+    use crate::{Fnv1a, Fnv1aBuildHasher, FnvBuildHasher, RandomFnvState};
+
+    /// A `hashbrown::HashMap` using a default FNV hasher.
+    pub type FnvHashMap<K, V, T, F = Fnv1a> = HashMap<K, V, FnvBuildHasher<T, F>>;
+
+    /// A `hashbrown::HashSet` using a default FNV hasher.
+    pub type FnvHashSet<K, T, F = Fnv1a> = HashSet<K, FnvBuildHasher<T, F>>;
+
+    /// A `hashbrown::HashMap` using a default FNV-1a hasher.
+    pub type Fnv1aHashMap<K, V> = HashMap<K, V, Fnv1aBuildHasher>;
+
+    /// A `hashbrown::HashSet` using a default FNV-1a hasher.
+    pub type Fnv1aHashSet<T> = HashSet<T, Fnv1aBuildHasher>;
+
+    /// A `hashbrown::HashMap` using a randomly keyed FNV-1a hasher.
+    pub type RandomFnvHashMap<K, V> = HashMap<K, V, RandomFnvState>;
+
+    /// A `hashbrown::HashSet` using a randomly keyed FNV-1a hasher.
+    pub type RandomFnvHashSet<T> = HashSet<T, RandomFnvState>;
+}
+
+/// [`indexmap`](https://docs.rs/indexmap) aliases
+#[cfg(feature = "indexmap")]
+pub mod indexmap {
+    use ::indexmap::{IndexMap, IndexSet};
+
+    use crate::{Fnv1a, Fnv1aBuildHasher, FnvBuildHasher};
+
+    /// An `IndexMap` using a default FNV hasher.
+    pub type FnvIndexMap<K, V, T, F = Fnv1a> = IndexMap<K, V, FnvBuildHasher<T, F>>;
+
+    /// An `IndexSet` using a default FNV hasher.
+    pub type FnvIndexSet<K, T, F = Fnv1a> = IndexSet<K, FnvBuildHasher<T, F>>;
+
+    /// An `IndexMap` using a default FNV-1a hasher.
+    pub type Fnv1aIndexMap<K, V> = IndexMap<K, V, Fnv1aBuildHasher>;
+
+    /// An `IndexSet` using a default FNV-1a hasher.
+    pub type Fnv1aIndexSet<T> = IndexSet<T, Fnv1aBuildHasher>;
+}
+
+/// [`dashmap`](https://docs.rs/dashmap) aliases
+#[cfg(feature = "dashmap")]
+pub mod dashmap {
+    use ::dashmap::{DashMap, DashSet};
+
+    use crate::{Fnv1a, Fnv1aBuildHasher, FnvBuildHasher};
+
+    /// A `DashMap` using a default FNV hasher.
+    pub type FnvDashMap<K, V, T, F = Fnv1a> = DashMap<K, V, FnvBuildHasher<T, F>>;
+
+    /// A `DashSet` using a default FNV hasher.
+    pub type FnvDashSet<K, T, F = Fnv1a> = DashSet<K, FnvBuildHasher<T, F>>;
+
+    /// A `DashMap` using a default FNV-1a hasher.
+    pub type Fnv1aDashMap<K, V> = DashMap<K, V, Fnv1aBuildHasher>;
+
+    /// A `DashSet` using a default FNV-1a hasher.
+    pub type Fnv1aDashSet<T> = DashSet<T, Fnv1aBuildHasher>;
+}
+
+/// [`heapless`](https://docs.rs/heapless) aliases
+///
+/// These use a 32 bit FNV-1a state which also implements [`hash32::Hasher`].
+/// The builder is const constructible. Hence the maps and sets can be created
+/// in a `const` context.
+///
+/// ```
+/// use yafnv::collections::heapless::Fnv1aIndexMap;
+///
+/// static EMPTY: Fnv1aIndexMap<u8, u8, 4> = Fnv1aIndexMap::new();
+/// assert!(EMPTY.is_empty());
+///
+/// const MAP: Fnv1aIndexMap<u8, u8, 4> = Fnv1aIndexMap::new();
+/// let mut map = MAP;
+/// map.insert(1, 2).unwrap();
+/// assert_eq!(map.get(&1), Some(&2));
+/// ```
+#[cfg(feature = "hash32")]
+pub mod heapless {
+    use ::heapless::{IndexMap, IndexSet};
+    use hash32::BuildHasherDefault;
+
+    use crate::{Fnv1a, FnvHasher};
+
+    /// A const constructible builder for a 32 bit FNV-1a hasher.
+    pub type Fnv1a32BuildHasher = BuildHasherDefault<FnvHasher<u32, Fnv1a>>;
+
+    /// A `heapless::IndexMap` using a 32 bit FNV-1a hasher.
+    pub type Fnv1aIndexMap<K, V, const N: usize> = IndexMap<K, V, Fnv1a32BuildHasher, N>;
+
+    /// A `heapless::IndexSet` using a 32 bit FNV-1a hasher.
+    pub type Fnv1aIndexSet<T, const N: usize> = IndexSet<T, Fnv1a32BuildHasher, N>;
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "hashbrown")]
+    #[test]
+    fn hashbrown() {
+        let mut m = super::hashbrown::Fnv1aHashMap::default();
+        m.insert("foo", 1);
+        assert_eq!(m["foo"], 1);
+        let mut s = super::hashbrown::FnvHashSet::<_, u32>::default();
+        assert!(s.insert(1));
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn indexmap() {
+        let mut m = super::indexmap::Fnv1aIndexMap::default();
+        m.insert("foo", 1);
+        m.insert("bar", 2);
+        assert_eq!(m.get_index(1), Some((&"bar", &2)));
+    }
+
+    #[cfg(feature = "dashmap")]
+    #[test]
+    fn dashmap() {
+        let m = super::dashmap::Fnv1aDashMap::default();
+        m.insert("foo", 1);
+        assert_eq!(*m.get("foo").unwrap(), 1);
+    }
+
+    #[cfg(feature = "hash32")]
+    #[test]
+    fn heapless() {
+        use core::hash::{BuildHasher, Hasher};
+        use hash32::Hasher as _;
+
+        let b = super::heapless::Fnv1a32BuildHasher::new();
+        let mut h = b.build_hasher();
+        h.write(b"foobar");
+        assert_eq!(h.finish32(), crate::fnv1a::<u32>(b"foobar"));
+        assert_eq!(h.finish(), crate::fnv1a::<u32>(b"foobar") as u64);
+
+        let mut m = super::heapless::Fnv1aIndexMap::<_, _, 4>::new();
+        m.insert("foo", 1).unwrap();
+        m.insert("bar", 2).unwrap();
+        assert_eq!(m.get("bar"), Some(&2));
+        let mut s = super::heapless::Fnv1aIndexSet::<_, 2>::new();
+        assert_eq!(s.insert(1), Ok(true));
+    }
+}
@@ -0,0 +1,105 @@
+//! RustCrypto [`digest`](https://docs.rs/digest) trait implementations
+//!
+//! The output is the big-endian representation of the full width hash.
+
+use core::hash::Hasher;
+use digest::typenum::{U128, U16, U32, U4, U64, U8};
+use digest::{FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update};
+use num_traits::AsPrimitive;
+
+use crate::{Fnv, FnvHasher, Variant, U1024, U256, U512};
+
+impl<T, V> Update for FnvHasher<T, V>
+where
+    T: Fnv + AsPrimitive<u64>,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.write(data);
+    }
+}
+
+impl<T, V> Reset for FnvHasher<T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    /// Reset to the default state, discarding any key.
+    #[inline]
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl<T, V> HashMarker for FnvHasher<T, V> {}
+
+macro_rules! digest {
+    ($ty:ty, $size:ty) => {
+        impl<V> OutputSizeUser for FnvHasher<$ty, V> {
+            type OutputSize = $size;
+        }
+
+        impl<V: Variant> FixedOutput for FnvHasher<$ty, V> {
+            #[inline]
+            fn finalize_into(self, out: &mut Output<Self>) {
+                out.copy_from_slice(&self.finish_full().to_be_bytes());
+            }
+        }
+
+        impl<V: Variant> FixedOutputReset for FnvHasher<$ty, V> {
+            #[inline]
+            fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+                out.copy_from_slice(&self.finish_full().to_be_bytes());
+                Reset::reset(self);
+            }
+        }
+    };
+}
+
+digest!(u32, U4);
+digest!(u64, U8);
+digest!(u128, U16);
+digest!(U256, U32);
+digest!(U512, U64);
+digest!(U1024, U128);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{fnv1, fnv1a, Fnv1, Fnv1a};
+    use digest::Digest;
+
+    #[test]
+    fn digest() {
+        assert_eq!(
+            FnvHasher::<u32, Fnv1a>::digest(b"foobar")[..],
+            fnv1a::<u32>(b"foobar").to_be_bytes()
+        );
+        assert_eq!(
+            FnvHasher::<u64, Fnv1a>::digest(b"foobar")[..],
+            fnv1a::<u64>(b"foobar").to_be_bytes()
+        );
+        assert_eq!(
+            FnvHasher::<u128, Fnv1>::digest(b"foobar")[..],
+            fnv1::<u128>(b"foobar").to_be_bytes()
+        );
+        assert_eq!(
+            FnvHasher::<U256, Fnv1a>::digest(b"foobar")[..],
+            fnv1a::<U256>(b"foobar").to_be_bytes()
+        );
+    }
+
+    #[test]
+    fn reset() {
+        let mut h = FnvHasher::<u64, Fnv1a>::new();
+        Digest::update(&mut h, b"foo");
+        Digest::update(&mut h, b"bar");
+        let out = h.finalize_reset();
+        assert_eq!(out[..], fnv1a::<u64>(b"foobar").to_be_bytes());
+        Digest::update(&mut h, b"a");
+        assert_eq!(h.finalize()[..], fnv1a::<u64>(b"a").to_be_bytes());
+    }
+}
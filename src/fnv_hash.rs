@@ -0,0 +1,439 @@
+//! Stable structural hashing
+//!
+//! [`core::hash::Hash`] makes no guarantees about the data fed to the hasher across
+//! Rust versions and platforms. [`FnvHash`] defines a canonical encoding instead.
+//! Hashes computed with it are suitable for persistence.
+
+use num_traits::AsPrimitive;
+
+use crate::{Fnv, Variant};
+
+/// Stable structural FNV hashing
+///
+/// Values are encoded canonically and the encoding is fed to [`Variant::update`].
+/// The encoding is frozen: it will not change without a major version bump.
+///
+/// * `bool`: one byte, `0` or `1`
+/// * Integers: fixed width little-endian bytes. `usize` and `isize` are widened
+///   to 64 bits.
+/// * `char`: the code point as `u32`
+/// * `f32` and `f64`: the little-endian bits after normalization: `-0.0` is
+///   encoded as `0.0` and all NaNs as the canonical quiet NaN
+///   (`0x7fc0_0000` and `0x7ff8_0000_0000_0000`).
+/// * `str`: the length in bytes as `u64` followed by the UTF-8 bytes
+/// * Slices: the length as `u64` followed by the elements
+/// * Arrays, tuples, and structs: the elements or fields in order without length
+/// * `()` and unit structs: nothing
+/// * `Option`: `0u8` for `None` and `1u8` followed by the value for `Some`
+/// * `Result`: `0u8` followed by the value for `Ok` and `1u8` followed by the error
+///   for `Err`
+/// * References and smart pointers: the encoding of the referenced value
+/// * Enums: the discriminant as `u32` followed by the fields of the variant.
+///   The discriminant is the index of the variant in declaration order unless
+///   specified with `#[fnv_hash(discriminant = N)]`. Specifying it for one variant
+///   does not change the others. Discriminants must be distinct.
+///
+/// Field and type names are not encoded.
+///
+/// With the `derive` feature, `#[derive(FnvHash)]` implements this trait for structs and enums.
+///
+/// ```
+/// use yafnv::{fnv_hash, Fnv1a, FnvHash};
+///
+/// struct Config {
+///     name: &'static str,
+///     rate: Option<u32>,
+/// }
+///
+/// impl FnvHash for Config {
+///     fn fnv_hash<T, V>(&self, state: T) -> T
+///     where
+///         T: yafnv::Fnv,
+///         u8: num_traits::AsPrimitive<T>,
+///         V: yafnv::Variant,
+///     {
+///         let state = self.name.fnv_hash::<T, V>(state);
+///         self.rate.fnv_hash::<T, V>(state)
+///     }
+/// }
+///
+/// let config = Config { name: "adc", rate: Some(1000) };
+/// let encoding = [3, 0, 0, 0, 0, 0, 0, 0, b'a', b'd', b'c', 1, 0xe8, 0x03, 0, 0];
+/// assert_eq!(fnv_hash::<u64, Fnv1a>(&config), yafnv::fnv1a::<u64>(&encoding));
+/// ```
+pub trait FnvHash {
+    /// Update the FNV `state` with the canonical encoding of `self`.
+    fn fnv_hash<T, V>(&self, state: T) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        V: Variant;
+}
+
+/// Compute the stable structural FNV hash of a value.
+///
+/// The hash starts from the [`Variant::offset_basis`]. See [`FnvHash`].
+pub fn fnv_hash<T, V>(value: &(impl FnvHash + ?Sized)) -> T
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    value.fnv_hash::<T, V>(V::offset_basis())
+}
+
+macro_rules! int {
+    ($($ty:ty),*) => {
+        $(
+            impl FnvHash for $ty {
+                #[inline]
+                fn fnv_hash<T, V>(&self, state: T) -> T
+                where
+                    T: Fnv,
+                    u8: AsPrimitive<T>,
+                    V: Variant,
+                {
+                    V::update(state, self.to_le_bytes())
+                }
+            }
+        )*
+    };
+}
+
+int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl FnvHash for usize {
+    #[inline]
+    fn fnv_hash<T, V>(&self, state: T) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        V: Variant,
+    {
+        (*self as u64).fnv_hash::<T, V>(state)
+    }
+}
+
+impl FnvHash for isize {
+    #[inline]
+    fn fnv_hash<T, V>(&self, state: T) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        V: Variant,
+    {
+        (*self as i64).fnv_hash::<T, V>(state)
+    }
+}
+
+impl FnvHash for bool {
+    #[inline]
+    fn fnv_hash<T, V>(&self, state: T) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        V: Variant,
+    {
+        (*self as u8).fnv_hash::<T, V>(state)
+    }
+}
+
+impl FnvHash for char {
+    #[inline]
+    fn fnv_hash<T, V>(&self, state: T) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        V: Variant,
+    {
+        (*self as u32).fnv_hash::<T, V>(state)
+    }
+}
+
+impl FnvHash for f32 {
+    #[inline]
+    fn fnv_hash<T, V>(&self, state: T) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        V: Variant,
+    {
+        let bits = if self.is_nan() {
+            0x7fc0_0000
+        } else if *self == 0.0 {
+            0
+        } else {
+            self.to_bits()
+        };
+        bits.fnv_hash::<T, V>(state)
+    }
+}
+
+impl FnvHash for f64 {
+    #[inline]
+    fn fnv_hash<T, V>(&self, state: T) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        V: Variant,
+    {
+        let bits = if self.is_nan() {
+            0x7ff8_0000_0000_0000
+        } else if *self == 0.0 {
+            0
+        } else {
+            self.to_bits()
+        };
+        bits.fnv_hash::<T, V>(state)
+    }
+}
+
+impl FnvHash for str {
+    #[inline]
+    fn fnv_hash<T, V>(&self, state: T) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        V: Variant,
+    {
+        let state = (self.len() as u64).fnv_hash::<T, V>(state);
+        V::update(state, self.bytes())
+    }
+}
+
+impl<H: FnvHash> FnvHash for [H] {
+    #[inline]
+    fn fnv_hash<T, V>(&self, state: T) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        V: Variant,
+    {
+        let state = (self.len() as u64).fnv_hash::<T, V>(state);
+        self.iter()
+            .fold(state, |state, h| h.fnv_hash::<T, V>(state))
+    }
+}
+
+impl<H: FnvHash, const N: usize> FnvHash for [H; N] {
+    #[inline]
+    fn fnv_hash<T, V>(&self, state: T) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        V: Variant,
+    {
+        self.iter()
+            .fold(state, |state, h| h.fnv_hash::<T, V>(state))
+    }
+}
+
+impl<H: FnvHash + ?Sized> FnvHash for &H {
+    #[inline]
+    fn fnv_hash<T, V>(&self, state: T) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        V: Variant,
+    {
+        (**self).fnv_hash::<T, V>(state)
+    }
+}
+
+impl<H: FnvHash + ?Sized> FnvHash for &mut H {
+    #[inline]
+    fn fnv_hash<T, V>(&self, state: T) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        V: Variant,
+    {
+        (**self).fnv_hash::<T, V>(state)
+    }
+}
+
+impl<H: FnvHash> FnvHash for Option<H> {
+    #[inline]
+    fn fnv_hash<T, V>(&self, state: T) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        V: Variant,
+    {
+        match self {
+            None => 0u8.fnv_hash::<T, V>(state),
+            Some(h) => h.fnv_hash::<T, V>(1u8.fnv_hash::<T, V>(state)),
+        }
+    }
+}
+
+impl<H: FnvHash, E: FnvHash> FnvHash for Result<H, E> {
+    #[inline]
+    fn fnv_hash<T, V>(&self, state: T) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        V: Variant,
+    {
+        match self {
+            Ok(h) => h.fnv_hash::<T, V>(0u8.fnv_hash::<T, V>(state)),
+            Err(e) => e.fnv_hash::<T, V>(1u8.fnv_hash::<T, V>(state)),
+        }
+    }
+}
+
+macro_rules! tuple {
+    ($($name:ident)*) => {
+        impl<$($name: FnvHash),*> FnvHash for ($($name,)*) {
+            #[inline]
+            #[allow(non_snake_case, unused_variables)]
+            fn fnv_hash<T, V>(&self, state: T) -> T
+            where
+                T: Fnv,
+                u8: AsPrimitive<T>,
+                V: Variant,
+            {
+                let ($($name,)*) = self;
+                $(let state = $name.fnv_hash::<T, V>(state);)*
+                state
+            }
+        }
+    };
+}
+
+tuple!();
+tuple!(A);
+tuple!(A B);
+tuple!(A B C);
+tuple!(A B C D);
+tuple!(A B C D E);
+tuple!(A B C D E F);
+tuple!(A B C D E F G);
+tuple!(A B C D E F G H);
+tuple!(A B C D E F G H I);
+tuple!(A B C D E F G H I J);
+tuple!(A B C D E F G H I J K);
+tuple!(A B C D E F G H I J K L);
+
+#[cfg(feature = "std")]
+mod std_impls {
+    use super::*;
+
+    impl FnvHash for String {
+        #[inline]
+        fn fnv_hash<T, V>(&self, state: T) -> T
+        where
+            T: Fnv,
+            u8: AsPrimitive<T>,
+            V: Variant,
+        {
+            self.as_str().fnv_hash::<T, V>(state)
+        }
+    }
+
+    impl<H: FnvHash> FnvHash for Vec<H> {
+        #[inline]
+        fn fnv_hash<T, V>(&self, state: T) -> T
+        where
+            T: Fnv,
+            u8: AsPrimitive<T>,
+            V: Variant,
+        {
+            self.as_slice().fnv_hash::<T, V>(state)
+        }
+    }
+
+    impl<H: FnvHash + ?Sized> FnvHash for Box<H> {
+        #[inline]
+        fn fnv_hash<T, V>(&self, state: T) -> T
+        where
+            T: Fnv,
+            u8: AsPrimitive<T>,
+            V: Variant,
+        {
+            (**self).fnv_hash::<T, V>(state)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{fnv1a, Fnv1a};
+
+    fn hash(value: &(impl FnvHash + ?Sized)) -> u64 {
+        fnv_hash::<u64, Fnv1a>(value)
+    }
+
+    #[test]
+    fn encoding() {
+        assert_eq!(hash(&true), fnv1a::<u64>(&[1]));
+        assert_eq!(hash(&0x1234u16), fnv1a::<u64>(&[0x34, 0x12]));
+        assert_eq!(hash(&-1isize), fnv1a::<u64>(&[0xff; 8]));
+        assert_eq!(hash(&1usize), hash(&1u64));
+        assert_eq!(hash(&'a'), hash(&0x61u32));
+        assert_eq!(
+            hash("ab"),
+            fnv1a::<u64>(&[2, 0, 0, 0, 0, 0, 0, 0, b'a', b'b'])
+        );
+        assert_eq!(hash(&[1u8, 2][..]), hash("\x01\x02"));
+        assert_eq!(hash(&[1u8, 2]), fnv1a::<u64>(&[1, 2]));
+        assert_eq!(hash(&(1u8, 2u8)), hash(&[1u8, 2]));
+        assert_eq!(hash(&()), fnv1a::<u64>(&[]));
+        assert_eq!(hash(&None::<u8>), fnv1a::<u64>(&[0]));
+        assert_eq!(hash(&Some(5u8)), fnv1a::<u64>(&[1, 5]));
+        assert_eq!(hash(&Ok::<u8, u16>(5)), fnv1a::<u64>(&[0, 5]));
+        assert_eq!(hash(&Err::<u8, u16>(5)), fnv1a::<u64>(&[1, 5, 0]));
+        assert_eq!(hash(&&5u8), hash(&5u8));
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq!(hash(&-0.0f32), hash(&0.0f32));
+        assert_eq!(hash(&-0.0f64), hash(&0u64));
+        assert_eq!(hash(&f32::NAN), hash(&-f32::NAN));
+        assert_eq!(hash(&f64::NAN), hash(&0x7ff8_0000_0000_0000u64));
+        assert_eq!(hash(&1.0f32), hash(&0x3f80_0000u32));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive() {
+        use yafnv_derive::FnvHash;
+
+        #[derive(FnvHash)]
+        struct Unit;
+
+        #[derive(FnvHash)]
+        struct Named<'a, T> {
+            a: u8,
+            b: &'a [T],
+        }
+
+        #[derive(FnvHash)]
+        struct Tuple(u16, bool);
+
+        #[derive(FnvHash)]
+        enum Enum {
+            A,
+            B(u8),
+            #[fnv_hash(discriminant = 7)]
+            C {
+                x: i8,
+            },
+            D,
+        }
+
+        #[derive(FnvHash)]
+        #[allow(dead_code)]
+        enum Empty {}
+
+        assert_eq!(hash(&Unit), hash(&()));
+        assert_eq!(hash(&Named { a: 1, b: &[2u8] }), hash(&(1u8, &[2u8][..])));
+        assert_eq!(hash(&Tuple(3, true)), hash(&(3u16, true)));
+        assert_eq!(hash(&Enum::A), hash(&0u32));
+        assert_eq!(hash(&Enum::B(5)), hash(&(1u32, 5u8)));
+        assert_eq!(hash(&Enum::C { x: -1 }), hash(&(7u32, -1i8)));
+        assert_eq!(hash(&Enum::D), hash(&3u32));
+    }
+}
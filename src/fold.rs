@@ -0,0 +1,99 @@
+//! XOR-folding
+//!
+//! To obtain an `N` bit hash where `N` is not one of the FNV sizes, the
+//! [FNV draft](https://datatracker.ietf.org/doc/draft-eastlake-fnv/21/) recommends
+//! computing the hash with the smallest FNV size that is at least `N` bits wide
+//! and then xor-folding the excess high bits onto the low bits.
+//!
+//! ```
+//! use yafnv::{fnv1a, xor_fold};
+//!
+//! // A 24 bit FNV-1a hash
+//! let hash = xor_fold(fnv1a::<u32>(b"foobar"), 24);
+//! assert_eq!(hash, 0x9cf9d7);
+//! ```
+
+use num_traits::PrimInt;
+
+/// XOR-fold a hash to its `bits` least significant bits.
+///
+/// This computes `((hash >> bits) ^ hash) & ((1 << bits) - 1)`. If `bits` is at least
+/// half the width of `T`, all high bits are folded. Otherwise the bits above `2*bits`
+/// are discarded as described in the FNV draft for "tiny" hashes.
+///
+/// # Panics
+/// If `bits` is zero or larger than the width of `T`.
+#[inline]
+pub fn xor_fold<T: PrimInt>(hash: T, bits: u32) -> T {
+    let width = T::zero().count_zeros();
+    assert!(bits > 0 && bits <= width);
+    if bits == width {
+        hash
+    } else {
+        let mask = (T::one() << bits as usize) - T::one();
+        ((hash >> bits as usize) ^ hash) & mask
+    }
+}
+
+macro_rules! const_fold {
+    ($ty:ty, $name:ident) => {
+        #[doc = concat!("XOR-fold a `", stringify!($ty), "` hash in a `const` context.")]
+        ///
+        /// See [`xor_fold()`].
+        pub const fn $name(hash: $ty, bits: u32) -> $ty {
+            assert!(bits > 0 && bits <= <$ty>::BITS);
+            if bits == <$ty>::BITS {
+                hash
+            } else {
+                ((hash >> bits) ^ hash) & ((1 << bits) - 1)
+            }
+        }
+    };
+}
+
+const_fold!(u32, xor_fold_32);
+const_fold!(u64, xor_fold_64);
+const_fold!(u128, xor_fold_128);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{fnv1a, fnv1a_32, fnv1a_64};
+
+    #[test]
+    fn fold() {
+        let h = fnv1a::<u32>(b"foobar");
+        assert_eq!(h, 0xbf9cf968);
+        assert_eq!(xor_fold(h, 32), h);
+        assert_eq!(xor_fold(h, 24), 0xbf ^ 0x9cf968);
+        assert_eq!(xor_fold(h, 16), 0xbf9c ^ 0xf968);
+        assert_eq!(xor_fold(h, 8), 0xf9 ^ 0x68);
+        assert_eq!(xor_fold(h, 1), 1 & ((h >> 1) ^ h));
+        let h = fnv1a::<u64>(b"foobar");
+        assert_eq!(xor_fold(h, 32), (h >> 32) ^ (h & 0xffff_ffff));
+        assert_eq!(xor_fold(h, 48), (h >> 48) ^ (h & 0xffff_ffff_ffff));
+        for bits in 1..=32 {
+            assert_eq!(
+                xor_fold_32(fnv1a_32(b"a"), bits),
+                xor_fold(fnv1a::<u32>(b"a"), bits)
+            );
+            assert!(xor_fold(fnv1a::<u32>(b"a"), bits) >> (bits - 1) <= 1);
+        }
+        for bits in 1..=64 {
+            assert_eq!(
+                xor_fold_64(fnv1a_64(b"a"), bits),
+                xor_fold(fnv1a::<u64>(b"a"), bits)
+            );
+        }
+        assert_eq!(
+            xor_fold(fnv1a::<u128>(b"a"), 100),
+            xor_fold_128(fnv1a::<u128>(b"a"), 100)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn fold_too_wide() {
+        xor_fold(0u32, 33);
+    }
+}
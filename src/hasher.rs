@@ -0,0 +1,356 @@
+use core::borrow::Borrow;
+use core::hash::{BuildHasher, BuildHasherDefault, Hasher};
+use core::marker::PhantomData;
+use num_traits::AsPrimitive;
+#[cfg(feature = "std")]
+use std::collections::{hash_map::RandomState, HashMap, HashSet};
+
+use crate::Fnv;
+
+/// FNV variant
+///
+/// Selects the offset basis and the update step of a [`FnvHasher`].
+pub trait Variant {
+    /// The initial hasher state
+    #[inline]
+    fn offset_basis<T>() -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+    {
+        T::OFFSET_BASIS
+    }
+
+    /// Update the hasher state with `data`
+    fn update<T, I>(state: T, data: I) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        I: IntoIterator,
+        I::Item: Borrow<u8>;
+}
+
+/// The historic FNV-0 variant
+///
+/// See [`Fnv::fnv0`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Fnv0;
+
+impl Variant for Fnv0 {
+    #[inline]
+    fn offset_basis<T>() -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+    {
+        0u8.as_()
+    }
+
+    #[inline]
+    fn update<T, I>(state: T, data: I) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        I: IntoIterator,
+        I::Item: Borrow<u8>,
+    {
+        state.fnv1(data)
+    }
+}
+
+/// The FNV-1 variant
+///
+/// See [`Fnv::fnv1`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Fnv1;
+
+impl Variant for Fnv1 {
+    #[inline]
+    fn update<T, I>(state: T, data: I) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        I: IntoIterator,
+        I::Item: Borrow<u8>,
+    {
+        state.fnv1(data)
+    }
+}
+
+/// The FNV-1a variant
+///
+/// See [`Fnv::fnv1a`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Fnv1a;
+
+impl Variant for Fnv1a {
+    #[inline]
+    fn update<T, I>(state: T, data: I) -> T
+    where
+        T: Fnv,
+        u8: AsPrimitive<T>,
+        I: IntoIterator,
+        I::Item: Borrow<u8>,
+    {
+        state.fnv1a(data)
+    }
+}
+
+/// Fowler-Noll-Vo Hasher
+///
+/// The state `T` can be any [`Fnv`] type and the variant `V` is one of
+/// [`Fnv0`], [`Fnv1`], or [`Fnv1a`].
+///
+/// [`Hasher::finish()`] truncates the state to `u64`.
+/// Use [`FnvHasher::finish_full()`] to obtain the full width hash.
+///
+/// ```
+/// use core::hash::Hasher;
+/// use yafnv::{Fnv1, FnvHasher};
+///
+/// // Test vector from https://datatracker.ietf.org/doc/draft-eastlake-fnv/21/
+/// let mut h = FnvHasher::<u32, Fnv1>::default();
+/// h.write("foobar".as_bytes());
+/// assert_eq!(h.finish_full(), 0x31f0b262);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct FnvHasher<T, V = Fnv1a> {
+    state: T,
+    variant: PhantomData<V>,
+}
+
+impl<T, V> FnvHasher<T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    /// Create an FNV hasher starting with a state corresponding
+    /// to the hash `key`.
+    #[inline]
+    pub fn with_key(key: T) -> Self {
+        Self {
+            state: key,
+            variant: PhantomData,
+        }
+    }
+
+    /// Return the full width hash of the values written so far.
+    #[inline]
+    pub fn finish_full(&self) -> T {
+        self.state
+    }
+}
+
+impl<T, V> Default for FnvHasher<T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::with_key(V::offset_basis())
+    }
+}
+
+impl<T, V> Hasher for FnvHasher<T, V>
+where
+    T: Fnv + AsPrimitive<u64>,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.state.as_()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.state = V::update(self.state, bytes);
+    }
+}
+
+#[cfg(feature = "hash32")]
+impl<V: Variant> hash32::Hasher for FnvHasher<u32, V> {
+    #[inline]
+    fn finish32(&self) -> u32 {
+        self.state
+    }
+}
+
+/// Fowler-Noll-Vo FNV-1a Hasher
+///
+/// ```
+/// use core::hash::Hasher;
+/// use yafnv::Fnv1aHasher;
+///
+/// // Test vector from https://datatracker.ietf.org/doc/draft-eastlake-fnv/21/
+/// let mut h = Fnv1aHasher::default();
+/// h.write("foobar".as_bytes());
+/// assert_eq!(h.finish(), 0x85944171f73967e8);
+/// ```
+pub type Fnv1aHasher = FnvHasher<u64, Fnv1a>;
+
+/// A builder for default FNV hashers.
+pub type FnvBuildHasher<T, V = Fnv1a> = BuildHasherDefault<FnvHasher<T, V>>;
+
+/// A builder for default FNV-1a hasher.
+pub type Fnv1aBuildHasher = FnvBuildHasher<u64, Fnv1a>;
+
+/// A builder for randomly keyed FNV-1a hashers
+///
+/// The hasher state starts from a per-instance secret key derived from a seed.
+/// This makes hash values and collisions unpredictable to an attacker that does not
+/// know the seed. It is a mitigation against "hash flooding" but not a guarantee
+/// comparable to a keyed cryptographic hash like SipHash.
+///
+/// With the `std` feature, `RandomFnvState::new()` and [`Default`] seed from
+/// the OS randomness used by `std::collections::hash_map::RandomState`.
+/// Otherwise use [`RandomFnvState::with_seed()`] with a seed from an entropy source.
+///
+/// ```
+/// use core::hash::BuildHasher;
+/// use yafnv::RandomFnvState;
+///
+/// // A seed from a hardware RNG
+/// let seed = 0x0123456789abcdef;
+/// let s = RandomFnvState::with_seed(seed);
+/// assert_eq!(s.hash_one("foo"), RandomFnvState::with_seed(seed).hash_one("foo"));
+/// assert_ne!(s.hash_one("foo"), RandomFnvState::with_seed(!seed).hash_one("foo"));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct RandomFnvState {
+    key: u64,
+}
+
+impl RandomFnvState {
+    /// Create a new builder seeded from OS randomness.
+    #[cfg(feature = "std")]
+    pub fn new() -> Self {
+        Self::with_seed(RandomState::new().build_hasher().finish())
+    }
+
+    /// Create a new builder from a random seed.
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            key: u64::OFFSET_BASIS.fnv1a(seed.to_le_bytes()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for RandomFnvState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for RandomFnvState {
+    type Hasher = Fnv1aHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Fnv1aHasher {
+        Fnv1aHasher::with_key(self.key)
+    }
+}
+
+/// A `HashMap` using a default FNV hasher.
+#[cfg(feature = "std")]
+pub type FnvHashMap<K, V, T, F = Fnv1a> = HashMap<K, V, FnvBuildHasher<T, F>>;
+
+/// A `HashSet` using a default FNV hasher.
+#[cfg(feature = "std")]
+pub type FnvHashSet<K, T, F = Fnv1a> = HashSet<K, FnvBuildHasher<T, F>>;
+
+/// A `HashMap` using a default FNV-1a hasher.
+#[cfg(feature = "std")]
+pub type Fnv1aHashMap<K, V> = HashMap<K, V, Fnv1aBuildHasher>;
+
+/// A `HashSet` using a default FNV-1a hasher.
+#[cfg(feature = "std")]
+pub type Fnv1aHashSet<T> = HashSet<T, Fnv1aBuildHasher>;
+
+/// A `HashMap` using a randomly keyed FNV-1a hasher.
+#[cfg(feature = "std")]
+pub type RandomFnvHashMap<K, V> = HashMap<K, V, RandomFnvState>;
+
+/// A `HashSet` using a randomly keyed FNV-1a hasher.
+#[cfg(feature = "std")]
+pub type RandomFnvHashSet<T> = HashSet<T, RandomFnvState>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{fnv0, fnv1, fnv1a, U256};
+
+    fn hash<T, V>(data: &[u8]) -> T
+    where
+        T: Fnv + AsPrimitive<u64>,
+        u8: AsPrimitive<T>,
+        V: Variant,
+    {
+        let mut h = FnvHasher::<T, V>::default();
+        let (a, b) = data.split_at(data.len() / 2);
+        h.write(a);
+        h.write(b);
+        h.finish_full()
+    }
+
+    #[test]
+    fn variants() {
+        for data in ["", "a", "foobar", "chongo was here!\n"] {
+            let data = data.as_bytes();
+            assert_eq!(hash::<u32, Fnv0>(data), fnv0::<u32>(data));
+            assert_eq!(hash::<u32, Fnv1>(data), fnv1::<u32>(data));
+            assert_eq!(hash::<u32, Fnv1a>(data), fnv1a::<u32>(data));
+            assert_eq!(hash::<u64, Fnv1>(data), fnv1::<u64>(data));
+            assert_eq!(hash::<u64, Fnv1a>(data), fnv1a::<u64>(data));
+            assert_eq!(hash::<u128, Fnv1a>(data), fnv1a::<u128>(data));
+            assert_eq!(hash::<U256, Fnv1a>(data), fnv1a::<U256>(data));
+        }
+    }
+
+    #[test]
+    fn finish() {
+        let mut h = FnvHasher::<u128, Fnv1a>::default();
+        h.write(b"foobar");
+        assert_eq!(h.finish(), fnv1a::<u128>(b"foobar") as u64);
+        let mut h = FnvHasher::<u32, Fnv1a>::default();
+        h.write(b"foobar");
+        assert_eq!(h.finish(), 0xbf9cf968);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn map() {
+        let mut m = FnvHashMap::<_, _, u32>::default();
+        m.insert("foo", 1);
+        m.insert("bar", 2);
+        assert_eq!(m["foo"], 1);
+        let mut s = FnvHashSet::<_, u128, Fnv1>::default();
+        assert!(s.insert(3));
+        assert!(!s.insert(3));
+    }
+
+    #[test]
+    fn random() {
+        let s = RandomFnvState::with_seed(0);
+        let mut h = s.build_hasher();
+        h.write(b"foobar");
+        let key = fnv1a::<u64>(&0u64.to_le_bytes());
+        assert_eq!(h.finish(), key.fnv1a(b"foobar"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn random_std() {
+        let (a, b) = (RandomFnvState::new(), RandomFnvState::new());
+        assert_ne!(a.hash_one(1), b.hash_one(1));
+        let mut m = RandomFnvHashMap::default();
+        m.insert("foo", 1);
+        assert_eq!(m["foo"], 1);
+        let mut s = RandomFnvHashSet::default();
+        assert!(s.insert(1));
+    }
+}
@@ -0,0 +1,160 @@
+//! Compile time hashed identifiers
+
+/// Compute an FNV-1a identifier for a string at compile time.
+///
+/// * `fnv_id!("name")`: the 32 bit FNV-1a hash as a `u32`
+/// * `fnv_id!(u64, "name")`: the hash with the width of `u32`, `u64`, or `u128`
+/// * `fnv_id!(u32, 24, "name")`: the hash [xor-folded](crate::xor_fold) to `24` bits
+///
+/// The name is any `const` expression with an `as_bytes()` method, e.g. `&str`.
+/// The identifier is always evaluated in a `const` context.
+///
+/// ```
+/// use yafnv::fnv_id;
+///
+/// const SAMPLE_RATE: u32 = fnv_id!("adc.sample_rate");
+/// assert_eq!(SAMPLE_RATE, yafnv::fnv1a::<u32>(b"adc.sample_rate"));
+/// assert_eq!(fnv_id!(u64, "foobar"), 0x85944171f73967e8);
+/// assert_eq!(fnv_id!(u32, 24, "foobar"), 0x9cf9d7);
+/// ```
+#[macro_export]
+macro_rules! fnv_id {
+    (u32, $name:expr) => {{
+        const ID: u32 = $crate::fnv1a_32($name.as_bytes());
+        ID
+    }};
+    (u64, $name:expr) => {{
+        const ID: u64 = $crate::fnv1a_64($name.as_bytes());
+        ID
+    }};
+    (u128, $name:expr) => {{
+        const ID: u128 = $crate::fnv1a_128($name.as_bytes());
+        ID
+    }};
+    (u32, $bits:expr, $name:expr) => {{
+        const ID: u32 = $crate::xor_fold_32($crate::fnv1a_32($name.as_bytes()), $bits);
+        ID
+    }};
+    (u64, $bits:expr, $name:expr) => {{
+        const ID: u64 = $crate::xor_fold_64($crate::fnv1a_64($name.as_bytes()), $bits);
+        ID
+    }};
+    (u128, $bits:expr, $name:expr) => {{
+        const ID: u128 = $crate::xor_fold_128($crate::fnv1a_128($name.as_bytes()), $bits);
+        ID
+    }};
+    ($name:expr) => {
+        $crate::fnv_id!(u32, $name)
+    };
+}
+
+/// Declare a set of FNV-1a identifier constants and check them for collisions.
+///
+/// The first line selects the type (`u32`, `u64`, or `u128`) and optionally the
+/// number of bits to [xor-fold](crate::xor_fold) to. Each following declaration
+/// defines a constant with the [`fnv_id!`](crate::fnv_id) of the name.
+///
+/// Compilation fails if any two identifiers in the set are equal.
+///
+/// ```
+/// yafnv::fnv_ids! {
+///     u32, 16;
+///     /// ADC sample rate
+///     pub const ADC_RATE = "adc.sample_rate";
+///     pub const DAC_RATE = "dac.sample_rate";
+///     const GAIN = "afe.gain";
+/// }
+///
+/// assert_eq!(ADC_RATE, yafnv::fnv_id!(u32, 16, "adc.sample_rate"));
+/// assert!(DAC_RATE < 1 << 16);
+/// ```
+///
+/// A collision is a compile error:
+///
+/// ```compile_fail
+/// yafnv::fnv_ids! {
+///     u64;
+///     const A = "a";
+///     const B = "a";
+/// }
+/// ```
+#[macro_export]
+macro_rules! fnv_ids {
+    (
+        $ty:tt;
+        $($(#[$meta:meta])* $vis:vis const $id:ident = $name:expr;)*
+    ) => {
+        $(
+            $(#[$meta])*
+            $vis const $id: $ty = $crate::fnv_id!($ty, $name);
+        )*
+        $crate::fnv_ids!(@check $($id),*);
+    };
+    (
+        $ty:tt, $bits:expr;
+        $($(#[$meta:meta])* $vis:vis const $id:ident = $name:expr;)*
+    ) => {
+        $(
+            $(#[$meta])*
+            $vis const $id: $ty = $crate::fnv_id!($ty, $bits, $name);
+        )*
+        $crate::fnv_ids!(@check $($id),*);
+    };
+    (@check $($id:ident),*) => {
+        const _: () = {
+            const IDS: &[u128] = &[$($id as u128),*];
+            $(
+                assert!(
+                    $crate::__private::count_id(IDS, $id as u128) == 1,
+                    concat!("FNV identifier `", stringify!($id), "` collides"),
+                );
+            )*
+        };
+    };
+}
+
+/// Number of occurrences of `id` in `ids`
+#[doc(hidden)]
+pub const fn count_id(ids: &[u128], id: u128) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < ids.len() {
+        if ids[i] == id {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{fnv1a, xor_fold};
+
+    #[test]
+    fn id() {
+        assert_eq!(fnv_id!("foobar"), 0xbf9cf968);
+        assert_eq!(fnv_id!(u32, "foobar"), fnv1a::<u32>(b"foobar"));
+        assert_eq!(fnv_id!(u128, "foobar"), fnv1a::<u128>(b"foobar"));
+        assert_eq!(
+            fnv_id!(u64, 40, "foobar"),
+            xor_fold(fnv1a::<u64>(b"foobar"), 40)
+        );
+        const NAME: &str = "foo";
+        assert_eq!(fnv_id!(NAME), fnv1a::<u32>(b"foo"));
+    }
+
+    #[test]
+    fn ids() {
+        fnv_ids! {
+            u64, 8;
+            const A = "a";
+            const B = "b";
+        }
+        assert_eq!(A, xor_fold(fnv1a::<u64>(b"a"), 8));
+        assert_ne!(A, B);
+        assert_eq!(count_id(&[1, 2, 1], 1), 2);
+        assert_eq!(count_id(&[1, 2, 1], 2), 1);
+    }
+}
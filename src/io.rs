@@ -0,0 +1,213 @@
+//! Hashing `std::io` adapters
+
+use core::hash::Hasher;
+use num_traits::AsPrimitive;
+use std::io;
+
+use crate::{Fnv, Fnv1a, FnvHasher, Variant};
+
+impl<T, V> io::Write for FnvHasher<T, V>
+where
+    T: Fnv + AsPrimitive<u64>,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Hasher::write(self, buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A writer that hashes all data written through it
+///
+/// ```
+/// use std::io::Write;
+/// use yafnv::{fnv1a, HashingWriter};
+///
+/// let mut w = HashingWriter::<_, u64>::new(Vec::new());
+/// w.write_all(b"foobar").unwrap();
+/// assert_eq!(w.hash(), fnv1a::<u64>(b"foobar"));
+/// assert_eq!(w.into_inner(), b"foobar");
+/// ```
+#[derive(Clone, Debug)]
+pub struct HashingWriter<W, T, V = Fnv1a> {
+    inner: W,
+    hasher: FnvHasher<T, V>,
+}
+
+impl<W, T, V> HashingWriter<W, T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    /// Wrap a writer using a default hasher.
+    pub fn new(inner: W) -> Self {
+        Self::with_hasher(inner, FnvHasher::default())
+    }
+
+    /// Wrap a writer using the given hasher.
+    pub fn with_hasher(inner: W, hasher: FnvHasher<T, V>) -> Self {
+        Self { inner, hasher }
+    }
+
+    /// The hash of the data written so far
+    pub fn hash(&self) -> T {
+        self.hasher.finish_full()
+    }
+
+    /// A reference to the hasher
+    pub fn hasher(&self) -> &FnvHasher<T, V> {
+        &self.hasher
+    }
+
+    /// A reference to the inner writer
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// A mutable reference to the inner writer
+    ///
+    /// Data written directly to the inner writer is not hashed.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Return the inner writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Return the inner writer and the hasher.
+    pub fn into_parts(self) -> (W, FnvHasher<T, V>) {
+        (self.inner, self.hasher)
+    }
+}
+
+impl<W, T, V> io::Write for HashingWriter<W, T, V>
+where
+    W: io::Write,
+    T: Fnv + AsPrimitive<u64>,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = self.inner.write(buf)?;
+        Hasher::write(&mut self.hasher, &buf[..len]);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A reader that hashes all data read through it
+///
+/// ```
+/// use std::io::Read;
+/// use yafnv::{fnv1, Fnv1, HashingReader};
+///
+/// let mut r = HashingReader::<_, u32, Fnv1>::new(&b"foobar"[..]);
+/// let mut buf = Vec::new();
+/// r.read_to_end(&mut buf).unwrap();
+/// assert_eq!(r.hash(), fnv1::<u32>(b"foobar"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct HashingReader<R, T, V = Fnv1a> {
+    inner: R,
+    hasher: FnvHasher<T, V>,
+}
+
+impl<R, T, V> HashingReader<R, T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    /// Wrap a reader using a default hasher.
+    pub fn new(inner: R) -> Self {
+        Self::with_hasher(inner, FnvHasher::default())
+    }
+
+    /// Wrap a reader using the given hasher.
+    pub fn with_hasher(inner: R, hasher: FnvHasher<T, V>) -> Self {
+        Self { inner, hasher }
+    }
+
+    /// The hash of the data read so far
+    pub fn hash(&self) -> T {
+        self.hasher.finish_full()
+    }
+
+    /// A reference to the hasher
+    pub fn hasher(&self) -> &FnvHasher<T, V> {
+        &self.hasher
+    }
+
+    /// A reference to the inner reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// A mutable reference to the inner reader
+    ///
+    /// Data read directly from the inner reader is not hashed.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Return the inner reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Return the inner reader and the hasher.
+    pub fn into_parts(self) -> (R, FnvHasher<T, V>) {
+        (self.inner, self.hasher)
+    }
+}
+
+impl<R, T, V> io::Read for HashingReader<R, T, V>
+where
+    R: io::Read,
+    T: Fnv + AsPrimitive<u64>,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.inner.read(buf)?;
+        Hasher::write(&mut self.hasher, &buf[..len]);
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{fnv1a, Fnv1, U256};
+
+    #[test]
+    fn copy() {
+        let data: Vec<u8> = (0..10000).map(|i| (i * 7) as u8).collect();
+        let mut r = HashingReader::<_, u128>::new(&data[..]);
+        let mut w = HashingWriter::<_, u128>::new(Vec::new());
+        io::copy(&mut r, &mut w).unwrap();
+        assert_eq!(r.hash(), fnv1a::<u128>(&data));
+        assert_eq!(w.hash(), fnv1a::<u128>(&data));
+        assert_eq!(w.into_inner(), data);
+    }
+
+    #[test]
+    fn hasher() {
+        let mut h = FnvHasher::<U256, Fnv1>::default();
+        io::copy(&mut &b"foobar"[..], &mut h).unwrap();
+        assert_eq!(h.finish_full(), crate::fnv1::<U256>(b"foobar"));
+    }
+}
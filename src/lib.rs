@@ -1,7 +1,63 @@
 //! Fowler-Noll-Vo Hashes
 //!
 //! The implementation here is fully `no_std` and `no_alloc` and implements both FNV-1 and FNV-1a
-//! for `u32`, `u64`, and `u128` hash sizes.
+//! for `u32`, `u64`, and `u128` hash sizes as well as for the 256, 512, and 1024 bit
+//! hash sizes using the [`U256`], [`U512`], and [`U1024`] types.
+//!
+//! ```
+//! use yafnv::{fnv1a, U256};
+//!
+//! let hash: [u8; 32] = fnv1a::<U256>("foobar".as_bytes()).to_be_bytes();
+//! assert_eq!(hash[..4], [0xb0, 0x55, 0xea, 0x2f]);
+//! ```
+//!
+//! The input is any iterator of bytes or byte references. Wider symbols like `u16` words
+//! or `char`s can be hashed with [`le_bytes()`], [`be_bytes()`], and [`utf8_bytes()`].
+//!
+//! Non-standard primes and offset bases can be used with [`Custom`] and a [`Params`] set.
+//!
+//! FNV-1 and FNV-1a steps can be undone byte by byte with [`Unhash`].
+//!
+//! Primes for other sizes can be searched and validated with [`fnv_prime()`].
+//!
+//! Hashes of other sizes can be obtained by [xor-folding](xor_fold).
+//!
+//! Hashes can be mapped to arbitrary ranges with [`lazy_mod()`], [`retry_mod()`],
+//! or [`mul_shift_32()`].
+//!
+//! [`FnvMap`] and [`FnvSet`] are fixed capacity hash tables that need neither `std`
+//! nor an allocator.
+//!
+//! [`PhfMap`] is a static lookup table using a minimal perfect hash function [`Phf`].
+//!
+//! [`BloomFilter`] is a fixed size probabilistic set for cheap "seen before" checks.
+//!
+//! `const fn` variants allow hashing at compile time:
+//!
+//! ```
+//! const ID: u32 = yafnv::fnv1a_32("foobar".as_bytes());
+//! assert_eq!(ID, 0xbf9cf968);
+//! ```
+//!
+//! The [`fnv_id!`] and [`fnv_ids!`] macros derive numeric identifiers from names at
+//! compile time and check sets of them for collisions.
+//!
+//! Cargo features:
+//! * `std`: `HashMap`/`HashSet` aliases, `std::io` adapters, and construction of
+//!   FNV-1a preimages and collisions with `fnv1a_suffix()` and `fnv1a_collisions()`,
+//!   and perfect hash generation with `generate_phf()`
+//! * `digest`: RustCrypto [`digest`](https://docs.rs/digest) traits for [`FnvHasher`].
+//!   The output is the big-endian full width hash.
+//! * `hashbrown`, `indexmap`, `dashmap`: map and set aliases in the `collections` module.
+//!   Without `std`, the `hashbrown` aliases are re-exported as `Fnv1aHashMap` and `Fnv1aHashSet`.
+//!   `dashmap` implies `std`.
+//! * `hash32`: `hash32::Hasher` for 32 bit [`FnvHasher`] and `heapless` `IndexMap`/`IndexSet`
+//!   aliases in the `collections` module.
+//! * `cli`: the `yafnv` command line tool to hash files, standard input, or strings
+//!   with `sha256sum` compatible output.
+//! * `serde`: `hash_serialize()`
+//!   and a `serde::Serializer` hashing the serialized form of any value without allocating.
+//! * `derive`: `#[derive(FnvHash)]` for stable structural hashing with [`FnvHash`].
 //!
 //! See also the following crates:
 //! * [`fnv`](https://doc.servo.org/fnv/)
@@ -11,19 +67,101 @@
 #![warn(missing_docs, rust_2018_idioms)]
 #![forbid(unsafe_code)]
 
-use core::hash::{BuildHasherDefault, Hasher};
+use core::borrow::Borrow;
 use core::ops::BitXor;
 use num_traits::{AsPrimitive, WrappingMul};
+
+mod bloom;
+pub use bloom::BloomFilter;
+mod bytes;
+pub use bytes::{be_bytes, le_bytes, utf8_bytes};
+#[cfg(any(
+    feature = "hashbrown",
+    feature = "indexmap",
+    feature = "dashmap",
+    feature = "hash32"
+))]
+pub mod collections;
+#[cfg(feature = "dashmap")]
+pub use collections::dashmap::{Fnv1aDashMap, Fnv1aDashSet, FnvDashMap, FnvDashSet};
+#[cfg(all(feature = "hashbrown", not(feature = "std")))]
+pub use collections::hashbrown::{
+    Fnv1aHashMap, Fnv1aHashSet, FnvHashMap, FnvHashSet, RandomFnvHashMap, RandomFnvHashSet,
+};
+#[cfg(feature = "indexmap")]
+pub use collections::indexmap::{Fnv1aIndexMap, Fnv1aIndexSet, FnvIndexMap, FnvIndexSet};
+#[cfg(feature = "digest")]
+mod digest;
+#[cfg(feature = "derive")]
+pub use yafnv_derive::FnvHash;
+mod fnv_hash;
+pub use fnv_hash::{fnv_hash, FnvHash};
+mod fold;
+pub use fold::{xor_fold, xor_fold_128, xor_fold_32, xor_fold_64};
+mod hasher;
+pub use hasher::{
+    Fnv0, Fnv1, Fnv1a, Fnv1aBuildHasher, Fnv1aHasher, FnvBuildHasher, FnvHasher, RandomFnvState,
+    Variant,
+};
+#[cfg(feature = "std")]
+pub use hasher::{
+    Fnv1aHashMap, Fnv1aHashSet, FnvHashMap, FnvHashSet, RandomFnvHashMap, RandomFnvHashSet,
+};
+mod id;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "std")]
+pub use io::{HashingReader, HashingWriter};
+mod map;
+pub use map::{
+    CapacityError, Entry, FnvMap, FnvSet, IntoIter, Iter, IterMut, OccupiedEntry, VacantEntry,
+};
+mod params;
+pub use params::{ConstParams, Custom, Params};
+mod phf;
+#[cfg(feature = "std")]
+pub use phf::{generate_phf, GeneratedPhf};
+pub use phf::{Phf, PhfMap};
 #[cfg(feature = "std")]
-use std::collections::{HashMap, HashSet};
+mod preimage;
+#[cfg(feature = "std")]
+pub use preimage::{fnv1a_collisions, fnv1a_suffix, ALPHANUMERIC, PRINTABLE};
+mod portable;
+pub use portable::{
+    Portable, PortableFnv1aBuildHasher, PortableFnv1aHasher, PortableFnvBuildHasher,
+};
+mod prime;
+pub use prime::{fnv_prime, fnv_primes, is_fnv_prime, is_prime};
+mod range;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "serde")]
+pub use serde::{hash_serialize, Error as SerializeError, FnvSerializer};
+pub mod test_vectors;
+pub use range::{lazy_mod, mul_shift_32, mul_shift_64, retry_mod};
+mod unhash;
+pub use unhash::Unhash;
+mod wide;
+pub use wide::{U1024, U256, U512};
+
+// For the `FnvHash` derive tests
+#[cfg(all(test, feature = "derive"))]
+extern crate self as yafnv;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::id::count_id;
+    pub use num_traits::AsPrimitive;
+}
 
 /// Fowler-Noll-Vo Hashes
 ///
-/// Both FNV-1 and FNV-1a are provided.
+/// Both FNV-1 and FNV-1a are provided as well as the historic FNV-0.
 ///
 /// Note that:
 /// * FNV is not a cryptographic hash.
 /// * FNV is not resistant to "hash flooding" denial-of-service attacks.
+///   See [`RandomFnvState`] for a mitigation.
 pub trait Fnv
 where
     Self: 'static + Copy + WrappingMul + BitXor<Output = Self>,
@@ -34,14 +172,29 @@ where
     /// The FNV offset basis
     const OFFSET_BASIS: Self;
 
+    /// Compute the historic Fowler-Noll-Vo hash FNV-0
+    ///
+    /// This is FNV-1 starting from zero instead of the offset basis.
+    /// It should only be used to derive the offset basis (see [`offset_basis()`])
+    /// or for compatibility with legacy protocols.
+    #[inline]
+    fn fnv0<I>(data: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<u8>,
+    {
+        0u8.as_().fnv1(data)
+    }
+
     /// Compute the Fowler-Noll-Vo hash FNV-1 (multiply before xor)
     #[inline]
     fn fnv1<I>(self, data: I) -> Self
     where
-        I: IntoIterator<Item = u8>,
+        I: IntoIterator,
+        I::Item: Borrow<u8>,
     {
         data.into_iter().fold(self, |hash, byte| {
-            hash.wrapping_mul(&Self::PRIME) ^ byte.as_()
+            hash.wrapping_mul(&Self::PRIME) ^ byte.borrow().as_()
         })
     }
 
@@ -65,10 +218,11 @@ where
     #[inline]
     fn fnv1a<I>(self, data: I) -> Self
     where
-        I: IntoIterator<Item = u8>,
+        I: IntoIterator,
+        I::Item: Borrow<u8>,
     {
         data.into_iter().fold(self, |hash, byte| {
-            (hash ^ byte.as_()).wrapping_mul(&Self::PRIME)
+            (hash ^ byte.borrow().as_()).wrapping_mul(&Self::PRIME)
         })
     }
 }
@@ -86,81 +240,107 @@ impl Fnv for u128 {
     const OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
 }
 
+/// The seed string used to derive the FNV offset basis
+pub const OFFSET_BASIS_SEED: &[u8] = b"chongo <Landon Curt Noll> /\\../\\";
+
+/// Derive the FNV offset basis.
+///
+/// The offset basis is the FNV-0 hash of [`OFFSET_BASIS_SEED`].
+///
+/// ```
+/// use yafnv::{offset_basis, Fnv};
+///
+/// assert_eq!(offset_basis::<u32>(), u32::OFFSET_BASIS);
+/// ```
+pub fn offset_basis<T>() -> T
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+{
+    fnv0(OFFSET_BASIS_SEED)
+}
+
+/// Compute the historic FNV-0 hash.
+///
+/// See also [`Fnv::fnv0`].
+pub fn fnv0<T>(data: impl IntoIterator<Item = impl Borrow<u8>>) -> T
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+{
+    T::fnv0(data)
+}
+
 /// Compute the FNV-1 hash.
 ///
 /// See also [`Fnv::fnv1`].
 /// Uses the default [`Fnv::OFFSET_BASIS`].
-pub fn fnv1<T>(data: &[u8]) -> T
+pub fn fnv1<T>(data: impl IntoIterator<Item = impl Borrow<u8>>) -> T
 where
     T: Fnv,
     u8: AsPrimitive<T>,
 {
-    T::OFFSET_BASIS.fnv1(data.iter().copied())
+    T::OFFSET_BASIS.fnv1(data)
 }
 
 /// Compute the FNV-1a hash.
 ///
 /// See also [`Fnv::fnv1a`].
 /// Uses the default [`Fnv::OFFSET_BASIS`].
-pub fn fnv1a<T>(data: &[u8]) -> T
+pub fn fnv1a<T>(data: impl IntoIterator<Item = impl Borrow<u8>>) -> T
 where
     T: Fnv,
     u8: AsPrimitive<T>,
 {
-    T::OFFSET_BASIS.fnv1a(data.iter().copied())
-}
-
-/// Fowler-Noll-Vo FNV-1a Hasher
-///
-/// ```
-/// use core::hash::Hasher;
-/// use yafnv::Fnv1aHasher;
-///
-/// // Test vector from https://datatracker.ietf.org/doc/draft-eastlake-fnv/21/
-/// let mut h = Fnv1aHasher::default();
-/// h.write("foobar".as_bytes());
-/// assert_eq!(h.finish(), 0x85944171f73967e8);
-/// ```
-pub struct Fnv1aHasher(u64);
-
-impl Fnv1aHasher {
-    /// Create an FNV-1a hasher starting with a state corresponding
-    /// to the hash `key`.
-    #[inline]
-    pub fn with_key(key: u64) -> Fnv1aHasher {
-        Fnv1aHasher(key)
-    }
+    T::OFFSET_BASIS.fnv1a(data)
 }
 
-impl Default for Fnv1aHasher {
-    #[inline]
-    fn default() -> Fnv1aHasher {
-        Self::with_key(u64::OFFSET_BASIS)
-    }
-}
+macro_rules! const_fnv {
+    ($ty:ty, $bits:literal, $fnv0:ident, $fnv1:ident, $fnv1a:ident) => {
+        #[doc = concat!("Compute the historic ", $bits, " bit FNV-0 hash in a `const` context.")]
+        ///
+        #[doc = concat!("Identical to [`fnv0::<", stringify!($ty), ">()`](fnv0).")]
+        pub const fn $fnv0(data: &[u8]) -> $ty {
+            let mut hash: $ty = 0;
+            let mut i = 0;
+            while i < data.len() {
+                hash = hash.wrapping_mul(<$ty as Fnv>::PRIME) ^ data[i] as $ty;
+                i += 1;
+            }
+            hash
+        }
 
-impl Hasher for Fnv1aHasher {
-    #[inline]
-    fn finish(&self) -> u64 {
-        self.0
-    }
+        #[doc = concat!("Compute the ", $bits, " bit FNV-1 hash in a `const` context.")]
+        ///
+        #[doc = concat!("Identical to [`fnv1::<", stringify!($ty), ">()`](fnv1).")]
+        pub const fn $fnv1(data: &[u8]) -> $ty {
+            let mut hash = <$ty as Fnv>::OFFSET_BASIS;
+            let mut i = 0;
+            while i < data.len() {
+                hash = hash.wrapping_mul(<$ty as Fnv>::PRIME) ^ data[i] as $ty;
+                i += 1;
+            }
+            hash
+        }
 
-    #[inline]
-    fn write(&mut self, bytes: &[u8]) {
-        self.0 = self.0.fnv1a(bytes.iter().copied());
-    }
+        #[doc = concat!("Compute the ", $bits, " bit FNV-1a hash in a `const` context.")]
+        ///
+        #[doc = concat!("Identical to [`fnv1a::<", stringify!($ty), ">()`](fnv1a).")]
+        pub const fn $fnv1a(data: &[u8]) -> $ty {
+            let mut hash = <$ty as Fnv>::OFFSET_BASIS;
+            let mut i = 0;
+            while i < data.len() {
+                hash = (hash ^ data[i] as $ty).wrapping_mul(<$ty as Fnv>::PRIME);
+                i += 1;
+            }
+            hash
+        }
+    };
 }
 
-/// A builder for default FNV-1a hasher.
-pub type Fnv1aBuildHasher = BuildHasherDefault<Fnv1aHasher>;
-
-/// A `HashMap` using a default FNV-1a hasher.
-#[cfg(feature = "std")]
-pub type Fnv1aHashMap<K, V> = HashMap<K, V, Fnv1aBuildHasher>;
-
-/// A `HashSet` using a default FNV-1a hasher.
-#[cfg(feature = "std")]
-pub type Fnv1aHashSet<T> = HashSet<T, Fnv1aBuildHasher>;
+const_fnv!(u32, 32, fnv0_32, fnv1_32, fnv1a_32);
+const_fnv!(u64, 64, fnv0_64, fnv1_64, fnv1a_64);
+const_fnv!(u128, 128, fnv0_128, fnv1_128, fnv1a_128);
 
 #[cfg(test)]
 mod test {
@@ -179,4 +359,38 @@ mod test {
             assert_eq!(fnv1a::<u64>(data.as_bytes()), h64);
         }
     }
+
+    #[test]
+    fn const_fn() {
+        for data in ["", "a", "foobar", "chongo was here!\n"] {
+            let data = data.as_bytes();
+            assert_eq!(fnv0_32(data), fnv0::<u32>(data));
+            assert_eq!(fnv0_64(data), fnv0::<u64>(data));
+            assert_eq!(fnv0_128(data), fnv0::<u128>(data));
+            assert_eq!(fnv1_32(data), fnv1::<u32>(data));
+            assert_eq!(fnv1a_32(data), fnv1a::<u32>(data));
+            assert_eq!(fnv1_64(data), fnv1::<u64>(data));
+            assert_eq!(fnv1a_64(data), fnv1a::<u64>(data));
+            assert_eq!(fnv1_128(data), fnv1::<u128>(data));
+            assert_eq!(fnv1a_128(data), fnv1a::<u128>(data));
+        }
+    }
+
+    #[test]
+    fn fnv0_offset_basis() {
+        assert_eq!(OFFSET_BASIS_SEED.len(), 32);
+        assert_eq!(offset_basis::<u32>(), u32::OFFSET_BASIS);
+        assert_eq!(offset_basis::<u64>(), u64::OFFSET_BASIS);
+        assert_eq!(offset_basis::<u128>(), u128::OFFSET_BASIS);
+        assert_eq!(offset_basis::<U256>(), U256::OFFSET_BASIS);
+        assert_eq!(offset_basis::<U512>(), U512::OFFSET_BASIS);
+        assert_eq!(offset_basis::<U1024>(), U1024::OFFSET_BASIS);
+        const {
+            assert!(fnv0_32(OFFSET_BASIS_SEED) == u32::OFFSET_BASIS);
+            assert!(fnv0_64(OFFSET_BASIS_SEED) == u64::OFFSET_BASIS);
+            assert!(fnv0_128(OFFSET_BASIS_SEED) == u128::OFFSET_BASIS);
+        }
+        // FNV-0 of the empty string is zero
+        assert_eq!(fnv0::<u64>(b""), 0);
+    }
 }
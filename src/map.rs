@@ -0,0 +1,817 @@
+//! Fixed capacity hash map and set
+//!
+//! [`FnvMap`] and [`FnvSet`] store up to `N` entries in an array without allocating.
+//! They use open addressing with linear probing and backward shift deletion
+//! (no tombstones). The capacity is fixed at compile time.
+//! Inserting into a full table returns a [`CapacityError`] with the rejected item.
+
+use core::borrow::Borrow;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::iter::{FilterMap, FusedIterator};
+use core::{array, mem, slice};
+
+use crate::{xor_fold_64, Fnv1aBuildHasher};
+
+/// The table is full
+///
+/// Contains the item that could not be inserted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CapacityError<T>(pub T);
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("capacity exceeded")
+    }
+}
+
+impl<T: fmt::Debug> core::error::Error for CapacityError<T> {}
+
+type Slot<K, V> = Option<(K, V)>;
+
+/// A fixed capacity hash map
+///
+/// The map holds at most `N` entries inline. It uses a [`BuildHasher`] `S`,
+/// by default the 64 bit [`Fnv1aBuildHasher`]. Any FNV width and variant can be
+/// used with [`FnvBuildHasher`](crate::FnvBuildHasher).
+///
+/// Lookups are fast while the map is not close to full.
+/// Choose `N` with some headroom (e.g. 25 %) over the expected number of entries.
+///
+/// ```
+/// use yafnv::FnvMap;
+///
+/// let mut map: FnvMap<&str, u32, 4> = FnvMap::new();
+/// map.insert("a", 1).unwrap();
+/// *map.entry("b").unwrap().or_insert(0) += 2;
+/// assert_eq!(map.get("a"), Some(&1));
+/// assert_eq!(map.remove("b"), Some(2));
+/// assert_eq!(map.len(), 1);
+///
+/// for k in ["c", "d", "e"] {
+///     map.insert(k, 0).unwrap();
+/// }
+/// assert!(map.insert("f", 0).is_err());
+/// ```
+///
+/// A map can be created in a `const` context:
+///
+/// ```
+/// use core::hash::BuildHasherDefault;
+/// use yafnv::{FnvMap, FnvHasher};
+///
+/// static MAP: FnvMap<u8, u8, 8, BuildHasherDefault<FnvHasher<u32>>> =
+///     FnvMap::with_hasher(BuildHasherDefault::new());
+/// assert!(MAP.is_empty());
+/// ```
+#[derive(Clone)]
+pub struct FnvMap<K, V, const N: usize, S = Fnv1aBuildHasher> {
+    slots: [Slot<K, V>; N],
+    len: usize,
+    hasher: S,
+}
+
+impl<K, V, const N: usize, S: Default> FnvMap<K, V, N, S> {
+    /// Create an empty map.
+    pub fn new() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K, V, const N: usize, S: Default> Default for FnvMap<K, V, N, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, const N: usize, S> FnvMap<K, V, N, S> {
+    /// Create an empty map using the given hasher builder.
+    pub const fn with_hasher(hasher: S) -> Self {
+        Self {
+            slots: [const { None }; N],
+            len: 0,
+            hasher,
+        }
+    }
+
+    /// Return the hasher builder.
+    pub fn hasher(&self) -> &S {
+        &self.hasher
+    }
+
+    /// The maximum number of entries
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// The number of entries
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the map contains no entries
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether the map contains `N` entries
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Remove all entries.
+    pub fn clear(&mut self) {
+        self.slots.iter_mut().for_each(|slot| *slot = None);
+        self.len = 0;
+    }
+
+    /// Iterate over the entries in arbitrary order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            inner: self
+                .slots
+                .iter()
+                .filter_map(|slot| slot.as_ref().map(|(k, v)| (k, v))),
+        }
+    }
+
+    /// Iterate over the entries with mutable values in arbitrary order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            inner: self
+                .slots
+                .iter_mut()
+                .filter_map(|slot| slot.as_mut().map(|(k, v)| (&*k, v))),
+        }
+    }
+
+    /// Iterate over the keys in arbitrary order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    /// Iterate over the values in arbitrary order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    /// Iterate over mutable values in arbitrary order.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.iter_mut().map(|(_, v)| v)
+    }
+}
+
+impl<K: Hash + Eq, V, const N: usize, S: BuildHasher> FnvMap<K, V, N, S> {
+    /// The preferred slot of a key
+    fn home<Q: Hash + ?Sized>(&self, key: &Q) -> usize {
+        let hash = xor_fold_64(self.hasher.hash_one(key), 32);
+        ((hash * N as u64) >> 32) as _
+    }
+
+    /// The slot containing `key` or the first free slot in its probe sequence
+    fn find<Q>(&self, key: &Q) -> Result<usize, Option<usize>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let mut i = self.home(key);
+        for _ in 0..N {
+            match &self.slots[i] {
+                None => return Err(Some(i)),
+                Some((k, _)) if k.borrow() == key => return Ok(i),
+                _ => i = if i + 1 == N { 0 } else { i + 1 },
+            }
+        }
+        Err(None)
+    }
+
+    /// Insert a key-value pair.
+    ///
+    /// Returns the previous value if the key was present. If the map is full and the key
+    /// is not present, the pair is returned as an error.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, CapacityError<(K, V)>> {
+        match self.find(&key) {
+            Ok(i) => Ok(self.slots[i].as_mut().map(|(_, v)| mem::replace(v, value))),
+            Err(Some(i)) => {
+                self.slots[i] = Some((key, value));
+                self.len += 1;
+                Ok(None)
+            }
+            Err(None) => Err(CapacityError((key, value))),
+        }
+    }
+
+    /// Return a reference to the value of a key.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_key_value(key).map(|(_, v)| v)
+    }
+
+    /// Return the stored key and value of a key.
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let i = self.find(key).ok()?;
+        self.slots[i].as_ref().map(|(k, v)| (k, v))
+    }
+
+    /// Return a mutable reference to the value of a key.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let i = self.find(key).ok()?;
+        self.slots[i].as_mut().map(|(_, v)| v)
+    }
+
+    /// Whether the map contains a key
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).is_ok()
+    }
+
+    /// Remove a key and return its value.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    /// Remove a key and return the stored key and value.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let i = self.find(key).ok()?;
+        Some(self.remove_at(i))
+    }
+
+    /// Remove the entry in slot `hole` and close the gap in the probe sequences.
+    fn remove_at(&mut self, mut hole: usize) -> (K, V) {
+        let entry = self.slots[hole].take().unwrap();
+        self.len -= 1;
+        let mut i = hole;
+        loop {
+            i = if i + 1 == N { 0 } else { i + 1 };
+            let Some((k, _)) = &self.slots[i] else {
+                break;
+            };
+            // Move the entry into the hole if the hole is on its probe sequence
+            let home = self.home(k);
+            if (i + N - home) % N >= (i + N - hole) % N {
+                self.slots[hole] = self.slots[i].take();
+                hole = i;
+            }
+        }
+        entry
+    }
+
+    /// Return the entry of a key for in-place manipulation.
+    ///
+    /// If the map is full and the key is not present, the key is returned as an error.
+    pub fn entry(&mut self, key: K) -> Result<Entry<'_, K, V, N, S>, CapacityError<K>> {
+        match self.find(&key) {
+            Ok(index) => Ok(Entry::Occupied(OccupiedEntry { map: self, index })),
+            Err(Some(index)) => Ok(Entry::Vacant(VacantEntry {
+                map: self,
+                index,
+                key,
+            })),
+            Err(None) => Err(CapacityError(key)),
+        }
+    }
+}
+
+impl<K, V, const N: usize, S> fmt::Debug for FnvMap<K, V, N, S>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, const N: usize, S> PartialEq for FnvMap<K, V, N, S>
+where
+    K: Hash + Eq,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K, V, const N: usize, S> Eq for FnvMap<K, V, N, S>
+where
+    K: Hash + Eq,
+    V: Eq,
+    S: BuildHasher,
+{
+}
+
+/// Iterator over the entries of an [`FnvMap`]
+pub struct Iter<'a, K, V> {
+    #[allow(clippy::type_complexity)]
+    inner: FilterMap<slice::Iter<'a, Slot<K, V>>, fn(&'a Slot<K, V>) -> Option<(&'a K, &'a V)>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+/// Iterator over the entries of an [`FnvMap`] with mutable values
+pub struct IterMut<'a, K, V> {
+    #[allow(clippy::type_complexity)]
+    inner: FilterMap<
+        slice::IterMut<'a, Slot<K, V>>,
+        fn(&'a mut Slot<K, V>) -> Option<(&'a K, &'a mut V)>,
+    >,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl<K, V> FusedIterator for IterMut<'_, K, V> {}
+
+/// Owning iterator over the entries of an [`FnvMap`]
+pub struct IntoIter<K, V, const N: usize> {
+    inner: core::iter::Flatten<array::IntoIter<Slot<K, V>, N>>,
+}
+
+impl<K, V, const N: usize> Iterator for IntoIter<K, V, N> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl<K, V, const N: usize> FusedIterator for IntoIter<K, V, N> {}
+
+impl<'a, K, V, const N: usize, S> IntoIterator for &'a FnvMap<K, V, N, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, const N: usize, S> IntoIterator for &'a mut FnvMap<K, V, N, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, V, const N: usize, S> IntoIterator for FnvMap<K, V, N, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: self.slots.into_iter().flatten(),
+        }
+    }
+}
+
+/// An entry of an [`FnvMap`]
+///
+/// See [`FnvMap::entry()`].
+pub enum Entry<'a, K, V, const N: usize, S> {
+    /// The key is present
+    Occupied(OccupiedEntry<'a, K, V, N, S>),
+    /// The key is absent and there is space for it
+    Vacant(VacantEntry<'a, K, V, N, S>),
+}
+
+impl<'a, K: Hash + Eq, V, const N: usize, S: BuildHasher> Entry<'a, K, V, N, S> {
+    /// The key of the entry
+    pub fn key(&self) -> &K {
+        match self {
+            Self::Occupied(e) => e.key(),
+            Self::Vacant(e) => e.key(),
+        }
+    }
+
+    /// Insert `default` if the entry is vacant and return a reference to the value.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Insert the result of `default` if the entry is vacant and return a reference to the value.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Self::Occupied(e) => e.into_mut(),
+            Self::Vacant(e) => e.insert(default()),
+        }
+    }
+
+    /// Insert the default value if the entry is vacant and return a reference to the value.
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Modify the value if the entry is occupied.
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Self::Occupied(e) = &mut self {
+            f(e.get_mut());
+        }
+        self
+    }
+}
+
+/// An occupied entry of an [`FnvMap`]
+pub struct OccupiedEntry<'a, K, V, const N: usize, S> {
+    map: &'a mut FnvMap<K, V, N, S>,
+    index: usize,
+}
+
+impl<'a, K: Hash + Eq, V, const N: usize, S: BuildHasher> OccupiedEntry<'a, K, V, N, S> {
+    fn entry(&self) -> &(K, V) {
+        self.map.slots[self.index].as_ref().unwrap()
+    }
+
+    fn entry_mut(&mut self) -> &mut (K, V) {
+        self.map.slots[self.index].as_mut().unwrap()
+    }
+
+    /// The key of the entry
+    pub fn key(&self) -> &K {
+        &self.entry().0
+    }
+
+    /// A reference to the value
+    pub fn get(&self) -> &V {
+        &self.entry().1
+    }
+
+    /// A mutable reference to the value
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.entry_mut().1
+    }
+
+    /// Convert into a mutable reference to the value with the lifetime of the map.
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map.slots[self.index].as_mut().unwrap().1
+    }
+
+    /// Replace the value and return the previous one.
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Remove the entry and return the value.
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    /// Remove the entry and return the stored key and value.
+    pub fn remove_entry(self) -> (K, V) {
+        self.map.remove_at(self.index)
+    }
+}
+
+/// A vacant entry of an [`FnvMap`]
+pub struct VacantEntry<'a, K, V, const N: usize, S> {
+    map: &'a mut FnvMap<K, V, N, S>,
+    index: usize,
+    key: K,
+}
+
+impl<'a, K, V, const N: usize, S> VacantEntry<'a, K, V, N, S> {
+    /// The key of the entry
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Return the key.
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Insert a value and return a reference to it.
+    pub fn insert(self, value: V) -> &'a mut V {
+        let slot = &mut self.map.slots[self.index];
+        self.map.len += 1;
+        &mut slot.insert((self.key, value)).1
+    }
+}
+
+/// A fixed capacity hash set
+///
+/// This is an [`FnvMap`] with `()` values.
+///
+/// ```
+/// use yafnv::FnvSet;
+///
+/// let mut seen: FnvSet<u32, 16> = FnvSet::new();
+/// assert_eq!(seen.insert(0x1234), Ok(true));
+/// assert_eq!(seen.insert(0x1234), Ok(false));
+/// assert!(seen.contains(&0x1234));
+/// assert!(seen.remove(&0x1234));
+/// assert!(seen.is_empty());
+/// ```
+#[derive(Clone)]
+pub struct FnvSet<T, const N: usize, S = Fnv1aBuildHasher> {
+    map: FnvMap<T, (), N, S>,
+}
+
+impl<T, const N: usize, S: Default> FnvSet<T, N, S> {
+    /// Create an empty set.
+    pub fn new() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<T, const N: usize, S: Default> Default for FnvSet<T, N, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize, S> FnvSet<T, N, S> {
+    /// Create an empty set using the given hasher builder.
+    pub const fn with_hasher(hasher: S) -> Self {
+        Self {
+            map: FnvMap::with_hasher(hasher),
+        }
+    }
+
+    /// Return the hasher builder.
+    pub fn hasher(&self) -> &S {
+        self.map.hasher()
+    }
+
+    /// The maximum number of elements
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// The number of elements
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Whether the set contains no elements
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Whether the set contains `N` elements
+    pub const fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Remove all elements.
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// Iterate over the elements in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.map.keys()
+    }
+}
+
+impl<T: Hash + Eq, const N: usize, S: BuildHasher> FnvSet<T, N, S> {
+    /// Insert an element.
+    ///
+    /// Returns whether the element was newly inserted. If the set is full and the element
+    /// is not present, it is returned as an error.
+    pub fn insert(&mut self, value: T) -> Result<bool, CapacityError<T>> {
+        match self.map.entry(value) {
+            Ok(Entry::Occupied(_)) => Ok(false),
+            Ok(Entry::Vacant(e)) => {
+                e.insert(());
+                Ok(true)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Whether the set contains an element
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Return the stored element equal to `value`.
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get_key_value(value).map(|(k, _)| k)
+    }
+
+    /// Remove an element and return whether it was present.
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove(value).is_some()
+    }
+
+    /// Remove and return the stored element equal to `value`.
+    pub fn take<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove_entry(value).map(|(k, _)| k)
+    }
+}
+
+impl<T: Hash + Eq, const N: usize, S: BuildHasher> PartialEq for FnvSet<T, N, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T: Hash + Eq, const N: usize, S: BuildHasher> Eq for FnvSet<T, N, S> {}
+
+impl<T: fmt::Debug, const N: usize, S> fmt::Debug for FnvSet<T, N, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize, S> IntoIterator for FnvSet<T, N, S> {
+    type Item = T;
+    type IntoIter = core::iter::Map<IntoIter<T, (), N>, fn((T, ())) -> T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter().map(|(k, ())| k)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::FnvBuildHasher;
+    use core::hash::BuildHasherDefault;
+
+    /// Maps every key to the same slot
+    #[derive(Default)]
+    struct Constant;
+
+    impl core::hash::Hasher for Constant {
+        fn finish(&self) -> u64 {
+            0
+        }
+
+        fn write(&mut self, _bytes: &[u8]) {}
+    }
+
+    #[test]
+    fn map() {
+        let mut m: FnvMap<u32, u32, 8> = FnvMap::new();
+        for i in 0..8 {
+            assert_eq!(m.insert(i, i * 10), Ok(None));
+        }
+        assert!(m.is_full());
+        assert_eq!(m.insert(3, 33), Ok(Some(30)));
+        assert_eq!(m.insert(8, 80), Err(CapacityError((8, 80))));
+        assert_eq!(m.get(&3), Some(&33));
+        *m.get_mut(&4).unwrap() += 1;
+        assert_eq!(m.get(&4), Some(&41));
+        assert_eq!(m.remove(&3), Some(33));
+        assert_eq!(m.remove(&3), None);
+        assert_eq!(m.len(), 7);
+        for i in (0..8).filter(|&i| i != 3) {
+            assert!(m.contains_key(&i));
+        }
+        let mut sum = 0;
+        for (k, v) in &mut m {
+            *v += 1;
+            sum += k;
+        }
+        assert_eq!(sum, 28 - 3);
+        assert!(m.iter().all(|(k, v)| *v == k * 10 + 1 + (*k == 4) as u32));
+        let mut keys = [0; 7];
+        for (k, slot) in m.clone().into_iter().map(|(k, _)| k).zip(&mut keys) {
+            *slot = k;
+        }
+        keys.sort();
+        assert_eq!(keys, [0, 1, 2, 4, 5, 6, 7]);
+        m.clear();
+        assert!(m.is_empty());
+        assert_eq!(m.iter().count(), 0);
+    }
+
+    #[test]
+    fn collisions() {
+        // All keys probe linearly from the same slot
+        let mut m: FnvMap<u8, u8, 5, BuildHasherDefault<Constant>> = FnvMap::new();
+        for i in 0..5 {
+            m.insert(i, i).unwrap();
+        }
+        assert_eq!(m.remove(&1), Some(1));
+        for i in [0, 2, 3, 4] {
+            assert_eq!(m.get(&i), Some(&i));
+        }
+        assert_eq!(m.remove(&0), Some(0));
+        assert_eq!(m.remove(&4), Some(4));
+        assert_eq!(m.get(&2), Some(&2));
+        assert_eq!(m.get(&3), Some(&3));
+        m.insert(5, 5).unwrap();
+        assert_eq!(m.remove(&2), Some(2));
+        assert_eq!(m.len(), 2);
+        assert_eq!(m.get(&3), Some(&3));
+        assert_eq!(m.get(&5), Some(&5));
+    }
+
+    #[test]
+    fn wrap() {
+        // Entries wrap around from the last slot and are moved back on removal
+        let mut m: FnvMap<u32, (), 64, FnvBuildHasher<u32>> = FnvMap::new();
+        for i in 0..64 {
+            m.insert(i, ()).unwrap();
+        }
+        for i in (0..64).step_by(3) {
+            m.remove(&i).unwrap();
+        }
+        for i in 0..64 {
+            assert_eq!(m.contains_key(&i), i % 3 != 0);
+        }
+        let empty: FnvMap<u32, (), 0> = FnvMap::new();
+        assert_eq!(empty.get(&0), None);
+        assert!(FnvMap::<u32, (), 0>::new().insert(0, ()).is_err());
+    }
+
+    #[test]
+    fn entry() {
+        let mut m: FnvMap<&str, u32, 2> = FnvMap::default();
+        *m.entry("a").unwrap().or_insert(1) += 1;
+        *m.entry("a").unwrap().or_default() += 1;
+        assert_eq!(m.get("a"), Some(&3));
+        let e = m.entry("b").unwrap().and_modify(|v| *v = 0);
+        assert_eq!(e.key(), &"b");
+        assert_eq!(*e.or_insert_with(|| 7), 7);
+        assert_eq!(m.entry("c").err(), Some(CapacityError("c")));
+        match m.entry("a").unwrap() {
+            Entry::Occupied(mut e) => {
+                assert_eq!(e.insert(5), 3);
+                assert_eq!(e.remove_entry(), ("a", 5));
+            }
+            Entry::Vacant(_) => unreachable!(),
+        }
+        match m.entry("c").unwrap() {
+            Entry::Vacant(e) => assert_eq!(e.into_key(), "c"),
+            Entry::Occupied(_) => unreachable!(),
+        }
+        assert_eq!(m.len(), 1);
+    }
+
+    #[test]
+    fn set() {
+        let mut s: FnvSet<u64, 3> = FnvSet::new();
+        assert_eq!(s.insert(1), Ok(true));
+        assert_eq!(s.insert(2), Ok(true));
+        assert_eq!(s.insert(1), Ok(false));
+        assert_eq!(s.insert(3), Ok(true));
+        assert_eq!(s.insert(4), Err(CapacityError(4)));
+        assert_eq!(s.get(&2), Some(&2));
+        assert_eq!(s.take(&2), Some(2));
+        assert!(s.remove(&3));
+        let mut t = FnvSet::default();
+        t.insert(1).unwrap();
+        assert_eq!(s, t);
+        assert!(s.into_iter().eq([1]));
+    }
+}
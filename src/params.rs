@@ -0,0 +1,268 @@
+//! User-defined FNV parameter sets
+//!
+//! The standard prime and offset basis are tied to the state type through [`Fnv`].
+//! [`Custom`] wraps a state type and replaces them with the parameters of a [`Params`] set.
+//! Since `Custom` implements [`Fnv`], it can be used everywhere a standard state can:
+//! with [`fnv1a()`](crate::fnv1a), [`FnvHasher`](crate::FnvHasher),
+//! [`FnvBuildHasher`](crate::FnvBuildHasher) etc.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{BitXor, Mul};
+use num_traits::{AsPrimitive, WrappingMul};
+
+use crate::Fnv;
+
+/// A set of FNV parameters for the state type `T`
+///
+/// Implement this on a marker type to define a custom parameter set.
+/// See also [`ConstParams`].
+///
+/// ```
+/// use yafnv::{fnv1a, Custom, Params};
+///
+/// struct Proto;
+///
+/// impl Params<u32> for Proto {
+///     const PRIME: u32 = 0x01000193;
+///     const OFFSET_BASIS: u32 = 0x12345678;
+/// }
+///
+/// let hash = fnv1a::<Custom<u32, Proto>>(b"foobar").get();
+/// assert_ne!(hash, fnv1a::<u32>(b"foobar"));
+/// ```
+pub trait Params<T> {
+    /// The FNV prime
+    const PRIME: T;
+    /// The FNV offset basis
+    const OFFSET_BASIS: T;
+}
+
+/// A parameter set given by const generics
+///
+/// Using a parameter that does not fit the state type is a compile time error.
+///
+/// ```
+/// use core::hash::Hasher;
+/// use yafnv::{ConstParams, Custom, FnvHasher};
+///
+/// type State = Custom<u64, ConstParams<0x100000001b3, 0x0123456789abcdef>>;
+/// let mut h = FnvHasher::<State>::default();
+/// h.write(b"foobar");
+/// assert_eq!(h.finish(), yafnv::fnv1a::<State>(b"foobar").get());
+/// ```
+///
+/// ```compile_fail
+/// use yafnv::{ConstParams, Custom};
+///
+/// // The prime does not fit into `u32`
+/// yafnv::fnv1a::<Custom<u32, ConstParams<0x100000001b3, 0>>>(b"foobar");
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConstParams<const PRIME: u128, const OFFSET_BASIS: u128>;
+
+macro_rules! const_params {
+    ($($ty:ty),*) => {
+        $(
+            impl<const P: u128, const B: u128> Params<$ty> for ConstParams<P, B> {
+                const PRIME: $ty = {
+                    assert!(P <= <$ty>::MAX as u128, "prime out of range");
+                    P as $ty
+                };
+                const OFFSET_BASIS: $ty = {
+                    assert!(B <= <$ty>::MAX as u128, "offset basis out of range");
+                    B as $ty
+                };
+            }
+        )*
+    };
+}
+
+const_params!(u32, u64, u128);
+
+/// An FNV state `T` using the parameter set `P`
+///
+/// The arithmetic is that of `T`. Only [`Fnv::PRIME`] and [`Fnv::OFFSET_BASIS`]
+/// are taken from `P`.
+#[repr(transparent)]
+pub struct Custom<T, P> {
+    state: T,
+    params: PhantomData<P>,
+}
+
+impl<T, P> Custom<T, P> {
+    /// Wrap a state.
+    #[inline]
+    pub const fn new(state: T) -> Self {
+        Self {
+            state,
+            params: PhantomData,
+        }
+    }
+
+    /// Return the state.
+    #[inline]
+    pub const fn get(self) -> T
+    where
+        T: Copy,
+    {
+        self.state
+    }
+}
+
+// Manual impls to avoid bounds on `P`
+impl<T: Copy, P> Copy for Custom<T, P> {}
+
+impl<T: Clone, P> Clone for Custom<T, P> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.state.clone())
+    }
+}
+
+impl<T: PartialEq, P> PartialEq for Custom<T, P> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<T: Eq, P> Eq for Custom<T, P> {}
+
+impl<T: Hash, P> Hash for Custom<T, P> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.state.hash(state)
+    }
+}
+
+impl<T: fmt::Debug, P> fmt::Debug for Custom<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.state.fmt(f)
+    }
+}
+
+impl<T: fmt::LowerHex, P> fmt::LowerHex for Custom<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.state.fmt(f)
+    }
+}
+
+impl<T: fmt::UpperHex, P> fmt::UpperHex for Custom<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.state.fmt(f)
+    }
+}
+
+impl<T: Mul<Output = T>, P> Mul for Custom<T, P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.state * rhs.state)
+    }
+}
+
+impl<T: WrappingMul, P> WrappingMul for Custom<T, P> {
+    #[inline]
+    fn wrapping_mul(&self, v: &Self) -> Self {
+        Self::new(self.state.wrapping_mul(&v.state))
+    }
+}
+
+impl<T: BitXor<Output = T>, P> BitXor for Custom<T, P> {
+    type Output = Self;
+
+    #[inline]
+    fn bitxor(self, rhs: Self) -> Self {
+        Self::new(self.state ^ rhs.state)
+    }
+}
+
+impl<T, P> AsPrimitive<Custom<T, P>> for u8
+where
+    T: 'static + Copy,
+    P: 'static,
+    u8: AsPrimitive<T>,
+{
+    #[inline]
+    fn as_(self) -> Custom<T, P> {
+        Custom::new(self.as_())
+    }
+}
+
+impl<T, P> AsPrimitive<u64> for Custom<T, P>
+where
+    T: AsPrimitive<u64>,
+    P: 'static,
+{
+    #[inline]
+    fn as_(self) -> u64 {
+        self.state.as_()
+    }
+}
+
+impl<T, P> Fnv for Custom<T, P>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    P: 'static + Params<T>,
+{
+    const PRIME: Self = Self::new(P::PRIME);
+    const OFFSET_BASIS: Self = Self::new(P::OFFSET_BASIS);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{fnv0, fnv1, fnv1a, Fnv1, FnvHasher, U256};
+
+    struct Standard;
+
+    impl<T: Fnv> Params<T> for Standard
+    where
+        u8: AsPrimitive<T>,
+    {
+        const PRIME: T = T::PRIME;
+        const OFFSET_BASIS: T = T::OFFSET_BASIS;
+    }
+
+    #[test]
+    fn standard() {
+        for data in ["", "a", "foobar"] {
+            let data = data.as_bytes();
+            assert_eq!(
+                fnv1a::<Custom<u32, Standard>>(data).get(),
+                fnv1a::<u32>(data)
+            );
+            assert_eq!(fnv1::<Custom<u64, Standard>>(data).get(), fnv1::<u64>(data));
+            assert_eq!(
+                fnv0::<Custom<u128, Standard>>(data).get(),
+                fnv0::<u128>(data)
+            );
+            assert_eq!(
+                fnv1a::<Custom<U256, Standard>>(data).get(),
+                fnv1a::<U256>(data)
+            );
+        }
+    }
+
+    #[test]
+    fn custom() {
+        type P = ConstParams<0x01000193, 0x12345678>;
+        assert_eq!(fnv1a::<Custom<u32, P>>(b"").get(), 0x12345678);
+        assert_eq!(
+            fnv1a::<Custom<u32, P>>(b"a").get(),
+            (0x12345678 ^ b'a' as u32).wrapping_mul(0x01000193)
+        );
+        assert_eq!(
+            fnv1::<Custom<u64, P>>(b"a").get(),
+            0x12345678u64.wrapping_mul(0x01000193) ^ b'a' as u64
+        );
+        let mut h = FnvHasher::<Custom<u32, P>, Fnv1>::default();
+        h.write(b"foo");
+        assert_eq!(h.finish_full().get(), fnv1::<Custom<u32, P>>(b"foo").get());
+        assert_eq!(h.finish(), h.finish_full().get() as u64);
+    }
+}
@@ -0,0 +1,330 @@
+//! Minimal perfect hashing
+//!
+//! A minimal perfect hash function maps each of `n` known keys to a distinct slot in `0..n`.
+//! The construction is CHD-like ("hash, displace, and compress"): a keyed FNV-1a pass over the key
+//! yields a hash `h`. A second pass over `h` yields a bucket `g` and a value `f1`.
+//! The [xor-fold](crate::xor_fold) of `h` yields an independent value `f2`.
+//! Each bucket has a displacement `(d1, d2)` and the slot is `(f2 + f1 * d1 + d2) % n`.
+//!
+//! The displacements are searched with [`generate_phf()`] (requires `std`), e.g. in `build.rs`.
+//! The result is emitted as Rust source code for a [`Phf`] that evaluates without `std`
+//! or allocation.
+
+use core::hash::Hasher;
+
+use crate::{mul_shift_32, xor_fold_64, Fnv, Fnv1aHasher};
+
+/// Bucket and displacement parameters of a key
+fn hashes(seed: u64, key: &[u8], buckets: usize, len: usize) -> (usize, u64, u64) {
+    let mut h = Fnv1aHasher::with_key(seed);
+    h.write(key);
+    let h = h.finish();
+    // A second pass diffuses the trailing key bytes into the high bits
+    let m = h.fnv1a(h.to_le_bytes());
+    let g = mul_shift_32((m >> 32) as u32, buckets as u32) as usize;
+    let f1 = (m as u32) as u64 % len as u64;
+    let f2 = xor_fold_64(h, 32) % len as u64;
+    (g, f1, f2)
+}
+
+/// Slot of a key in a bucket with displacement `d`
+fn displace((f1, f2): (u64, u64), (d1, d2): (u32, u32), len: usize) -> usize {
+    ((f2 + f1 * d1 as u64 + d2 as u64) % len as u64) as usize
+}
+
+/// A minimal perfect hash function
+///
+/// Maps each key of the set it was generated for to a distinct index in `0..len()`.
+/// Other keys are mapped to arbitrary indices. See [`PhfMap`] for a lookup table that
+/// rejects other keys.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Phf<'a> {
+    seed: u64,
+    len: usize,
+    displacements: &'a [(u32, u32)],
+}
+
+impl<'a> Phf<'a> {
+    /// Create a perfect hash function from generated parameters.
+    ///
+    /// See [`generate_phf()`].
+    ///
+    /// # Panics
+    /// If there are keys but no displacements.
+    pub const fn new(seed: u64, len: usize, displacements: &'a [(u32, u32)]) -> Self {
+        assert!(
+            len == 0 || !displacements.is_empty(),
+            "missing displacements"
+        );
+        Self {
+            seed,
+            len,
+            displacements,
+        }
+    }
+
+    /// The number of keys
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the key set is empty
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The index of a key
+    ///
+    /// Returns `None` if the key set is empty.
+    pub fn index(&self, key: &[u8]) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+        let (g, f1, f2) = hashes(self.seed, key, self.displacements.len(), self.len);
+        Some(displace((f1, f2), self.displacements[g], self.len))
+    }
+}
+
+/// A static map using a minimal perfect hash function
+///
+/// The entries are ordered by the index of their key.
+/// Keys are compared on lookup.
+///
+/// ```
+/// use yafnv::{Phf, PhfMap};
+///
+/// // Generated by `yafnv::generate_phf()`, e.g. in `build.rs`
+/// static SETTINGS: PhfMap<'static, &str, u32> = PhfMap::new(
+///     Phf::new(0xc7c2bf3b330983e6, 3, &[(0, 0)]),
+///     &[("afe.gain", 3), ("dac.rate", 2), ("adc.rate", 1)],
+/// );
+///
+/// assert_eq!(SETTINGS.get("dac.rate"), Some(&2));
+/// assert_eq!(SETTINGS.get("foo"), None);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct PhfMap<'a, K, V> {
+    phf: Phf<'a>,
+    entries: &'a [(K, V)],
+}
+
+impl<'a, K: AsRef<[u8]>, V> PhfMap<'a, K, V> {
+    /// Create a map from a perfect hash function and the entries in index order.
+    ///
+    /// # Panics
+    /// If the number of entries does not match the hash function.
+    pub const fn new(phf: Phf<'a>, entries: &'a [(K, V)]) -> Self {
+        assert!(phf.len() == entries.len(), "entry count mismatch");
+        Self { phf, entries }
+    }
+
+    /// The number of entries
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the map is empty
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Return the entry of a key.
+    pub fn get_entry(&self, key: impl AsRef<[u8]>) -> Option<&'a (K, V)> {
+        let key = key.as_ref();
+        let entry = &self.entries[self.phf.index(key)?];
+        (entry.0.as_ref() == key).then_some(entry)
+    }
+
+    /// Return the value of a key.
+    pub fn get(&self, key: impl AsRef<[u8]>) -> Option<&'a V> {
+        self.get_entry(key).map(|(_, v)| v)
+    }
+
+    /// Whether the map contains a key
+    pub fn contains_key(&self, key: impl AsRef<[u8]>) -> bool {
+        self.get_entry(key).is_some()
+    }
+
+    /// Iterate over the entries in index order.
+    pub fn entries(&self) -> impl Iterator<Item = &'a (K, V)> {
+        self.entries.iter()
+    }
+}
+
+#[cfg(feature = "std")]
+pub use generate::{generate_phf, GeneratedPhf};
+
+#[cfg(feature = "std")]
+mod generate {
+    use core::fmt;
+    use std::vec::Vec;
+
+    use super::{displace, hashes, Fnv, Phf};
+
+    /// Average number of keys per bucket
+    const LAMBDA: usize = 5;
+
+    /// Number of seeds to try
+    const SEEDS: u64 = 1 << 10;
+
+    /// Number of `d1` displacements to try per bucket before trying the next seed
+    const D1: u32 = 1 << 8;
+
+    /// A generated minimal perfect hash function
+    ///
+    /// [`Display`](fmt::Display) formats the [`Phf::new()`] expression.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct GeneratedPhf {
+        /// Hasher key
+        pub seed: u64,
+        /// Displacements of the buckets
+        pub displacements: Vec<(u32, u32)>,
+        /// Indices of the keys in slot order
+        pub order: Vec<usize>,
+    }
+
+    impl GeneratedPhf {
+        /// The perfect hash function
+        pub fn phf(&self) -> Phf<'_> {
+            Phf::new(self.seed, self.order.len(), &self.displacements)
+        }
+    }
+
+    impl fmt::Display for GeneratedPhf {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "::yafnv::Phf::new({:#018x}, {}, &{:?})",
+                self.seed,
+                self.order.len(),
+                self.displacements
+            )
+        }
+    }
+
+    /// Generate a minimal perfect hash function for a set of keys.
+    ///
+    /// Seeds are derived from a counter and the keyed FNV-1a hasher
+    /// (see [`Fnv1aHasher::with_key()`](crate::FnvHasher::with_key)) is used.
+    /// The generation is deterministic.
+    ///
+    /// Returns `None` if the keys are not distinct or no function was found.
+    ///
+    /// The result is typically used in `build.rs` to emit a [`PhfMap`](crate::PhfMap):
+    ///
+    /// ```
+    /// use yafnv::generate_phf;
+    ///
+    /// let keys = ["adc.rate", "dac.rate", "afe.gain"];
+    /// let phf = generate_phf(&keys).unwrap();
+    /// let entries: Vec<_> = phf.order.iter().map(|&i| format!("({:?}, {i})", keys[i])).collect();
+    /// let code = format!(
+    ///     "static SETTINGS: ::yafnv::PhfMap<&str, u32> = ::yafnv::PhfMap::new({phf}, &[{}]);",
+    ///     entries.join(", ")
+    /// );
+    /// assert!(code.starts_with("static SETTINGS: ::yafnv::PhfMap<&str, u32> = ::yafnv::PhfMap::new(::yafnv::Phf::new(0x"));
+    ///
+    /// for (i, key) in keys.iter().enumerate() {
+    ///     assert_eq!(phf.order[phf.phf().index(key.as_bytes()).unwrap()], i);
+    /// }
+    /// ```
+    pub fn generate_phf<K: AsRef<[u8]>>(keys: &[K]) -> Option<GeneratedPhf> {
+        let len = keys.len();
+        let buckets = len.div_ceil(LAMBDA).max(1);
+        (0..SEEDS).find_map(|i| {
+            let seed = u64::OFFSET_BASIS.fnv1a(i.to_le_bytes());
+            try_seed(keys, seed, buckets)
+        })
+    }
+
+    fn try_seed<K: AsRef<[u8]>>(keys: &[K], seed: u64, buckets: usize) -> Option<GeneratedPhf> {
+        let len = keys.len();
+        let mut members: Vec<Vec<(usize, (u64, u64))>> = (0..buckets).map(|_| Vec::new()).collect();
+        for (i, key) in keys.iter().enumerate() {
+            let (g, f1, f2) = hashes(seed, key.as_ref(), buckets, len);
+            // Keys in the same bucket with equal `f1` and `f2` can not be separated
+            if members[g].iter().any(|(_, f)| *f == (f1, f2)) {
+                return None;
+            }
+            members[g].push((i, (f1, f2)));
+        }
+        let mut by_size: Vec<usize> = (0..buckets).collect();
+        by_size.sort_by_key(|&g| core::cmp::Reverse(members[g].len()));
+        let mut displacements = vec![(0, 0); buckets];
+        let mut order = vec![usize::MAX; len];
+        let mut slots = Vec::new();
+        for g in by_size {
+            let bucket = &members[g];
+            if bucket.is_empty() {
+                break;
+            }
+            let d = (0..D1.min(len as u32))
+                .flat_map(|d1| (0..len as u32).map(move |d2| (d1, d2)))
+                .find(|&d| {
+                    slots.clear();
+                    bucket.iter().all(|&(_, f)| {
+                        let slot = displace(f, d, len);
+                        let free = order[slot] == usize::MAX && !slots.contains(&slot);
+                        slots.push(slot);
+                        free
+                    })
+                })?;
+            displacements[g] = d;
+            for &(i, f) in bucket {
+                order[displace(f, d, len)] = i;
+            }
+        }
+        Some(GeneratedPhf {
+            seed,
+            displacements,
+            order,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn map() {
+        static MAP: PhfMap<'static, &str, u32> = PhfMap::new(
+            Phf::new(0xa8c7f832281a39c5, 3, &[(0, 0)]),
+            &[("a", 1), ("c", 3), ("b", 2)],
+        );
+        assert_eq!(MAP.len(), 3);
+        assert!(MAP.entries().all(|(k, v)| MAP.get(k) == Some(v)));
+        assert!(!MAP.contains_key("d"));
+        let empty: PhfMap<'_, &str, ()> = PhfMap::new(Phf::new(0, 0, &[]), &[]);
+        assert_eq!(empty.get("a"), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn missing_displacements() {
+        Phf::new(0, 1, &[]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn generate() {
+        use std::{format, vec::Vec};
+
+        for n in [0, 1, 2, 10, 500] {
+            let keys: Vec<_> = (0..n)
+                .map(|i| format!("settings/channel{i}.rate"))
+                .collect();
+            let phf = generate_phf(&keys).unwrap();
+            let mut order = phf.order.clone();
+            order.sort();
+            assert!(order.iter().copied().eq(0..n));
+            let entries: Vec<_> = phf.order.iter().map(|&i| (keys[i].as_str(), i)).collect();
+            let map = PhfMap::new(phf.phf(), &entries);
+            for (i, key) in keys.iter().enumerate() {
+                assert_eq!(map.get(key), Some(&i));
+            }
+            assert_eq!(map.get("foo"), None);
+        }
+        assert_eq!(generate_phf(&["a", "a"]), None);
+    }
+}
@@ -0,0 +1,192 @@
+use core::hash::{BuildHasherDefault, Hasher};
+
+use crate::{Fnv1a, Fnv1aHasher, FnvHasher};
+
+/// Endianness and pointer width portable [`Hasher`] adapter
+///
+/// The default [`Hasher`] integer methods hash the native-endian bytes and
+/// `usize`/`isize` have platform dependent width. Hence the hashes of
+/// e.g. `#[derive(Hash)]` types differ between platforms.
+///
+/// This adapter writes all integers as fixed width little-endian bytes and widens
+/// `usize`/`isize` to 64 bits. Hashes are then identical on all platforms.
+///
+/// ```
+/// use core::hash::{BuildHasher, Hash};
+/// use yafnv::PortableFnv1aBuildHasher;
+///
+/// #[derive(Hash)]
+/// struct Key {
+///     id: u32,
+///     len: usize,
+/// }
+///
+/// let h = PortableFnv1aBuildHasher::default().hash_one(Key { id: 1, len: 2 });
+/// assert_eq!(h, yafnv::fnv1a::<u64>(&[1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]));
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Portable<H>(H);
+
+impl<H> Portable<H> {
+    /// Wrap a hasher.
+    #[inline]
+    pub fn new(inner: H) -> Self {
+        Self(inner)
+    }
+
+    /// A reference to the inner hasher
+    #[inline]
+    pub fn get_ref(&self) -> &H {
+        &self.0
+    }
+
+    /// Return the inner hasher.
+    #[inline]
+    pub fn into_inner(self) -> H {
+        self.0
+    }
+}
+
+impl<H: Hasher> Hasher for Portable<H> {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes)
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.0.write(&[i])
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.0.write(&i.to_le_bytes())
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.0.write(&i.to_le_bytes())
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.0.write(&i.to_le_bytes())
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.0.write(&i.to_le_bytes())
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as _)
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) {
+        self.write_u8(i as _)
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as _)
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as _)
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as _)
+    }
+
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as _)
+    }
+
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        // Sign extend
+        self.write_i64(i as _)
+    }
+}
+
+/// A portable FNV-1a hasher
+pub type PortableFnv1aHasher = Portable<Fnv1aHasher>;
+
+/// A builder for default portable FNV hashers.
+pub type PortableFnvBuildHasher<T, V = Fnv1a> = BuildHasherDefault<Portable<FnvHasher<T, V>>>;
+
+/// A builder for default portable FNV-1a hashers.
+pub type PortableFnv1aBuildHasher = BuildHasherDefault<PortableFnv1aHasher>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::hash::Hash;
+
+    fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+        let mut h = PortableFnv1aHasher::default();
+        value.hash(&mut h);
+        h.finish()
+    }
+
+    fn bytes(data: &[u8]) -> u64 {
+        let mut h = PortableFnv1aHasher::default();
+        h.write(data);
+        h.finish()
+    }
+
+    #[test]
+    fn integers() {
+        assert_eq!(hash(&0x1234u16), bytes(&[0x34, 0x12]));
+        assert_eq!(hash(&0x12345678u32), bytes(&[0x78, 0x56, 0x34, 0x12]));
+        assert_eq!(hash(&1usize), hash(&1u64));
+        assert_eq!(hash(&-1isize), hash(&-1i64));
+        assert_eq!(hash(&-1isize), bytes(&[0xff; 8]));
+        assert_eq!(hash(&-2i32), bytes(&[0xfe, 0xff, 0xff, 0xff]));
+        assert_eq!(hash(&1u128), bytes(&1u128.to_le_bytes()));
+    }
+
+    #[test]
+    fn slices() {
+        // Length prefix is a `usize`
+        let mut data = [0; 11];
+        data[0] = 3;
+        data[8..].copy_from_slice(&[1, 2, 3]);
+        assert_eq!(hash(&[1u8, 2, 3][..]), bytes(&data));
+    }
+
+    fn state<T: crate::Fnv + PartialEq + core::fmt::Debug>()
+    where
+        u8: num_traits::AsPrimitive<T>,
+        FnvHasher<T, crate::Fnv1>: Hasher + Default,
+    {
+        let mut h = Portable::new(FnvHasher::<T, crate::Fnv1>::default());
+        h.write_u32(0x12345678);
+        h.write_usize(1);
+        assert_eq!(
+            h.into_inner().finish_full(),
+            crate::fnv1::<T>(&[0x78, 0x56, 0x34, 0x12, 1, 0, 0, 0, 0, 0, 0, 0])
+        );
+    }
+
+    #[test]
+    fn widths() {
+        state::<u32>();
+        state::<u64>();
+        state::<u128>();
+        state::<crate::U256>();
+        state::<crate::U512>();
+        state::<crate::U1024>();
+    }
+}
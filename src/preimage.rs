@@ -0,0 +1,543 @@
+//! Preimages and collisions for FNV-1a
+//!
+//! FNV is not a cryptographic hash. Inputs with a given hash can be constructed efficiently.
+//! This is useful to test the handling of collisions in tables keyed by FNV hashes.
+//!
+//! An FNV-1a step `h' = (h ^ c) * P` can be written as `h' = (h + d) * P` where
+//! `d = (a ^ c) - a` is small and only depends on the byte `c` and the low byte `a` of the state.
+//! A suffix of `L` bytes maps a state `h` to `h * P^L + sum(d_i * P^(L - i))` modulo `2^n`.
+//! Small `d_i` hitting a given sum are found with a reduced basis of the lattice of solutions
+//! to `sum(d_i * P^(L - i)) = 0` (LLL and Babai's nearest plane algorithm).
+//! Then the `d_i` are converted back to bytes in the alphabet.
+//!
+//! The searches are deterministic and randomized only through their arguments.
+//! They support `u32` and `u64` states.
+
+use num_traits::AsPrimitive;
+
+use crate::{unhash, Fnv};
+
+/// Printable ASCII: space to `~`
+pub const PRINTABLE: &[u8] =
+    b" !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+
+/// ASCII letters and digits
+pub const ALPHANUMERIC: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Construct a suffix that takes an FNV-1a state to a target hash.
+///
+/// `state` is a state obtained from [`Fnv::fnv1a`], e.g. the hash of a prefix or the
+/// [`Fnv::OFFSET_BASIS`]. The returned suffix contains only bytes from `alphabet`
+/// (any byte if `None`) and satisfies `state.fnv1a(suffix) == target`.
+///
+/// Returns `None` if no suffix was found.
+/// All bytes, [`PRINTABLE`], and [`ALPHANUMERIC`] are supported.
+/// Searches with smaller alphabets (e.g. hexadecimal digits) are likely to fail.
+///
+/// ```
+/// use yafnv::{fnv1a, fnv1a_suffix, Fnv, PRINTABLE};
+///
+/// let state = u64::OFFSET_BASIS.fnv1a(b"user/");
+/// let suffix = fnv1a_suffix(state, 0x0123456789abcdef, Some(PRINTABLE)).unwrap();
+/// assert!(suffix.iter().all(|c| PRINTABLE.contains(c)));
+/// assert_eq!(state.fnv1a(&suffix), 0x0123456789abcdef);
+/// assert_eq!(fnv1a::<u64>([&b"user/"[..], &suffix].concat()), 0x0123456789abcdef);
+/// ```
+pub fn fnv1a_suffix<T>(state: T, target: T, alphabet: Option<&[u8]>) -> Option<Vec<u8>>
+where
+    T: Fnv + Into<u64>,
+    u8: AsPrimitive<T>,
+{
+    Solver::new::<T>(alphabet)?.suffix(state.into(), target.into())
+}
+
+/// Construct distinct suffixes that all take an FNV-1a state to the same hash.
+///
+/// Returns `count` distinct suffixes of equal length with bytes from `alphabet`
+/// (any byte if `None`) such that `state.fnv1a(suffix)` is the same for all of them.
+///
+/// The suffixes are combinations of colliding blocks.
+/// Their length grows with the logarithm of `count`.
+///
+/// Returns `None` if no collision was found.
+/// Alphabets down to hexadecimal digits are supported.
+///
+/// ```
+/// use yafnv::{fnv1a, fnv1a_collisions, Fnv, ALPHANUMERIC};
+///
+/// let keys = fnv1a_collisions(u32::OFFSET_BASIS, 5, Some(ALPHANUMERIC)).unwrap();
+/// assert_eq!(keys.len(), 5);
+/// let hash = fnv1a::<u32>(&keys[0]);
+/// assert!(keys.iter().all(|key| fnv1a::<u32>(key) == hash));
+/// ```
+pub fn fnv1a_collisions<T>(state: T, count: usize, alphabet: Option<&[u8]>) -> Option<Vec<Vec<u8>>>
+where
+    T: Fnv + Into<u64>,
+    u8: AsPrimitive<T>,
+{
+    let solver = Solver::new::<T>(alphabet)?;
+    let mut state = state.into();
+    let mut blocks = Vec::new();
+    while 1 << blocks.len() < count {
+        let (a, b) = solver.collision(state)?;
+        state = solver.hash(state, &a);
+        blocks.push([a, b]);
+    }
+    Some(
+        (0..count)
+            .map(|i| {
+                blocks
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(j, block)| &block[(i >> j) & 1])
+                    .copied()
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+/// Number of random trial prefixes
+const TRIALS: usize = 1 << 12;
+
+/// Length of the random trial prefixes
+const PREFIX: usize = 4;
+
+/// Number of basis vectors to enumerate around a close vector
+const ENUMERATE: usize = 6;
+
+/// Maximum number of nodes in a collision search
+const NODES: usize = 1 << 12;
+
+/// Search state for a width, prime, and alphabet
+struct Solver {
+    mask: u64,
+    prime: u64,
+    alphabet: Vec<u8>,
+    member: [bool; 256],
+    /// Most likely admissible difference `d = (a ^ c) - a` for a uniform `a`
+    center: i64,
+    /// Reduced basis of the lattice of `d` with `sum(d_i * P^(L - i)) = 0`
+    basis: Vec<Vec<i64>>,
+    /// Gram-Schmidt orthogonalization of `basis`
+    gs: Vec<Vec<f64>>,
+    /// Weights `P^(L - i)`
+    weights: Vec<u64>,
+}
+
+impl Solver {
+    fn new<T>(alphabet: Option<&[u8]>) -> Option<Self>
+    where
+        T: Fnv + Into<u64>,
+        u8: AsPrimitive<T>,
+    {
+        let bits = 8 * core::mem::size_of::<T>() as u32;
+        let mut member = [alphabet.is_none(); 256];
+        for &c in alphabet.unwrap_or_default() {
+            member[c as usize] = true;
+        }
+        let alphabet: Vec<u8> = (0..=255).filter(|&c| member[c as usize]).collect();
+        if alphabet.len() < 2 {
+            return None;
+        }
+        // Enough bytes to cover the hash space with margin, and a lattice of good quality
+        let len = (bits as f64 / (alphabet.len() as f64).log2() * 1.25).ceil() as usize;
+        let center = (-255..=255)
+            .max_by_key(|&d| {
+                (0..256)
+                    .filter(|&a| {
+                        let o = a + d;
+                        (0..256).contains(&o) && member[(o ^ a) as usize]
+                    })
+                    .count()
+            })
+            .unwrap();
+        let mask = u64::MAX >> (64 - bits);
+        let prime = T::PRIME.into();
+        let mut weights = Vec::with_capacity(len);
+        let mut w = prime;
+        for _ in 0..len {
+            weights.push(w);
+            w = w.wrapping_mul(prime) & mask;
+        }
+        weights.reverse();
+        let basis = kernel(&weights, bits);
+        let gs = gram_schmidt(&basis).0;
+        Some(Self {
+            mask,
+            prime,
+            alphabet,
+            member,
+            center,
+            basis,
+            gs,
+            weights,
+        })
+    }
+
+    fn hash(&self, state: u64, data: &[u8]) -> u64 {
+        data.iter().fold(state, |h, &c| {
+            (h ^ c as u64).wrapping_mul(self.prime) & self.mask
+        })
+    }
+
+    /// Random bytes from the alphabet
+    fn prefix(&self, rng: &mut u64) -> Vec<u8> {
+        (0..PREFIX)
+            .map(|_| self.alphabet[(splitmix(rng) % self.alphabet.len() as u64) as usize])
+            .collect()
+    }
+
+    fn suffix(&self, state: u64, target: u64) -> Option<Vec<u8>> {
+        let len = self.weights.len();
+        let mut rng = state ^ target.rotate_left(32);
+        let mut scale = self.prime;
+        for _ in 1..len {
+            scale = scale.wrapping_mul(self.prime);
+        }
+        let inv = inverse(self.prime);
+        for trial in 0..TRIALS {
+            let mut msg = if trial == 0 {
+                Vec::new()
+            } else {
+                self.prefix(&mut rng)
+            };
+            let state = self.hash(state, &msg);
+            // The low bytes of the first and the last state are known.
+            // Center the differences there on the admissible ones.
+            let mut center = vec![self.center; len];
+            center[0] = self.mean(state as u8);
+            center[len - 1] = -self.mean(target.wrapping_mul(inv) as u8);
+            // A particular solution for the last byte, moved close to the center
+            let rest = target.wrapping_sub(state.wrapping_mul(scale)) & self.mask;
+            let mut t: Vec<i128> = center.iter().map(|&c| -c as i128).collect();
+            t[len - 1] += (rest.wrapping_mul(inv) & self.mask) as i128;
+            self.babai(&mut t);
+            let mut d: Vec<i64> = t.iter().zip(&center).map(|(&t, c)| t as i64 + c).collect();
+            if self.enumerate(state, &mut d, ENUMERATE.min(len)) {
+                msg.extend(self.realize(state, &d).flatten());
+                return Some(msg);
+            }
+        }
+        None
+    }
+
+    /// Mean difference `(a ^ c) - a` over the alphabet
+    fn mean(&self, a: u8) -> i64 {
+        let sum: i64 = self
+            .alphabet
+            .iter()
+            .map(|&c| (a ^ c) as i64 - a as i64)
+            .sum();
+        sum / self.alphabet.len() as i64
+    }
+
+    /// Reduce `t` modulo the lattice to a short vector
+    fn babai(&self, t: &mut [i128]) {
+        // Repeat as the first pass is imprecise for large `t`
+        for _ in 0..4 {
+            let mut done = true;
+            for (b, g) in self.basis.iter().zip(&self.gs).rev() {
+                let num: f64 = t.iter().zip(g).map(|(&t, g)| t as f64 * g).sum();
+                let den: f64 = g.iter().map(|g| g * g).sum();
+                let q = (num / den).round() as i128;
+                if q != 0 {
+                    done = false;
+                    for (t, &b) in t.iter_mut().zip(b) {
+                        *t -= q * b as i128;
+                    }
+                }
+            }
+            if done {
+                break;
+            }
+        }
+    }
+
+    /// Search `d + sum(e_j * b_j)` with `e_j` in `-1..=1` for realizable differences
+    ///
+    /// On success `d` is realizable.
+    fn enumerate(&self, state: u64, d: &mut [i64], j: usize) -> bool {
+        let Some(j) = j.checked_sub(1) else {
+            return self.realize(state, d).all(|c| c.is_some());
+        };
+        let b = &self.basis[j];
+        if self.enumerate(state, d, j) {
+            return true;
+        }
+        for sign in [1, -2] {
+            d.iter_mut().zip(b).for_each(|(d, b)| *d += sign * b);
+            if self.enumerate(state, d, j) {
+                return true;
+            }
+        }
+        d.iter_mut().zip(b).for_each(|(d, b)| *d += b);
+        false
+    }
+
+    /// The bytes for the differences `d`, `None` where there is no byte in the alphabet
+    fn realize<'a>(
+        &'a self,
+        mut state: u64,
+        d: &'a [i64],
+    ) -> impl Iterator<Item = Option<u8>> + 'a {
+        d.iter().map(move |&d| {
+            let a = (state & 0xff) as i64;
+            let o = a + d;
+            if !(0..256).contains(&o) || !self.member[(o ^ a) as usize] {
+                return None;
+            }
+            let c = (o ^ a) as u8;
+            state = (state ^ c as u64).wrapping_mul(self.prime) & self.mask;
+            Some(c)
+        })
+    }
+
+    fn collision(&self, state: u64) -> Option<(Vec<u8>, Vec<u8>)> {
+        let mut rng = state;
+        for trial in 0..TRIALS {
+            let prefix = if trial == 0 {
+                Vec::new()
+            } else {
+                self.prefix(&mut rng)
+            };
+            let state = self.hash(state, &prefix);
+            for delta in &self.basis {
+                for sign in [1, -1] {
+                    let mut pair = (prefix.clone(), prefix.clone());
+                    let mut nodes = 0;
+                    if self.pair(state, state, delta, sign, &mut pair, &mut nodes, &mut rng) {
+                        return Some(pair);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Depth first search for two byte strings whose differences differ by `sign * delta`
+    #[allow(clippy::too_many_arguments)]
+    fn pair(
+        &self,
+        s0: u64,
+        s1: u64,
+        delta: &[i64],
+        sign: i64,
+        pair: &mut (Vec<u8>, Vec<u8>),
+        nodes: &mut usize,
+        rng: &mut u64,
+    ) -> bool {
+        let Some((&delta0, delta)) = delta.split_first() else {
+            debug_assert_eq!(s0, s1);
+            return true;
+        };
+        *nodes += 1;
+        if *nodes > NODES {
+            return false;
+        }
+        let (a0, a1) = ((s0 & 0xff) as i64, (s1 & 0xff) as i64);
+        let start = splitmix(rng) as usize;
+        for k in 0..self.alphabet.len() {
+            let c0 = self.alphabet[(start + k) % self.alphabet.len()];
+            let o1 = a1 + (a0 ^ c0 as i64) - a0 + sign * delta0;
+            if !(0..256).contains(&o1) || !self.member[(o1 ^ a1) as usize] {
+                continue;
+            }
+            let c1 = (o1 ^ a1) as u8;
+            pair.0.push(c0);
+            pair.1.push(c1);
+            if self.pair(
+                self.hash(s0, &[c0]),
+                self.hash(s1, &[c1]),
+                delta,
+                sign,
+                pair,
+                nodes,
+                rng,
+            ) {
+                return true;
+            }
+            pair.0.pop();
+            pair.1.pop();
+        }
+        false
+    }
+}
+
+/// SplitMix64 pseudo-random number generator
+fn splitmix(x: &mut u64) -> u64 {
+    *x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *x;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Multiplicative inverse of an odd `x` modulo `2^64`
+fn inverse(x: u64) -> u64 {
+    unhash::inverse(x as _) as _
+}
+
+/// LLL reduced basis of the lattice of `x` with `sum(x_i * w_i) = 0` modulo `2^bits`.
+///
+/// The congruence is lifted eight bits at a time to keep the entries small.
+/// `w` must contain an odd weight.
+fn kernel(w: &[u64], bits: u32) -> Vec<Vec<i64>> {
+    let n = w.len();
+    let mut basis: Vec<Vec<i64>> = (0..n)
+        .map(|i| (0..n).map(|j| (i == j) as i64).collect())
+        .collect();
+    for k in (0..bits).step_by(8) {
+        let s = (bits - k).min(8);
+        let m = (1 << s) - 1;
+        let r: Vec<u64> = basis
+            .iter()
+            .map(|v| {
+                let dot = v.iter().zip(w).fold(0u64, |acc, (&v, &w)| {
+                    acc.wrapping_add((v as u64).wrapping_mul(w))
+                });
+                (dot >> k) & m
+            })
+            .collect();
+        let p = r.iter().position(|r| r & 1 != 0).unwrap();
+        let inv = inverse(r[p]) & m;
+        let pivot = basis[p].clone();
+        for (j, v) in basis.iter_mut().enumerate() {
+            if j == p {
+                v.iter_mut().for_each(|v| *v <<= s);
+            } else {
+                let t = (r[j].wrapping_mul(inv) & m) as i64;
+                v.iter_mut().zip(&pivot).for_each(|(v, p)| *v -= t * p);
+            }
+        }
+        lll(&mut basis);
+    }
+    basis
+}
+
+/// Gram-Schmidt orthogonalization and coefficients
+fn gram_schmidt(basis: &[Vec<i64>]) -> (Vec<Vec<f64>>, Vec<Vec<f64>>) {
+    let n = basis.len();
+    let mut gs: Vec<Vec<f64>> = Vec::with_capacity(n);
+    let mut mu = vec![vec![0.0; n]; n];
+    for (i, b) in basis.iter().enumerate() {
+        let mut v: Vec<f64> = b.iter().map(|&b| b as f64).collect();
+        for (j, g) in gs.iter().enumerate() {
+            let den: f64 = g.iter().map(|g| g * g).sum();
+            mu[i][j] = b.iter().zip(g).map(|(&b, g)| b as f64 * g).sum::<f64>() / den;
+            v.iter_mut().zip(g).for_each(|(v, g)| *v -= mu[i][j] * g);
+        }
+        gs.push(v);
+    }
+    (gs, mu)
+}
+
+/// Lenstra-Lenstra-Lovász lattice basis reduction
+fn lll(basis: &mut [Vec<i64>]) {
+    let norm = |g: &[f64]| g.iter().map(|g| g * g).sum::<f64>();
+    let (mut gs, mut mu) = gram_schmidt(basis);
+    let mut k = 1;
+    while k < basis.len() {
+        for j in (0..k).rev() {
+            let q = mu[k][j].round();
+            if q != 0.0 {
+                let (head, tail) = basis.split_at_mut(k);
+                tail[0]
+                    .iter_mut()
+                    .zip(&head[j])
+                    .for_each(|(b, c)| *b -= q as i64 * c);
+                let (head, tail) = mu.split_at_mut(k);
+                tail[0][..j]
+                    .iter_mut()
+                    .zip(&head[j])
+                    .for_each(|(m, n)| *m -= q * n);
+                tail[0][j] -= q;
+            }
+        }
+        if norm(&gs[k]) >= (0.99 - mu[k][k - 1] * mu[k][k - 1]) * norm(&gs[k - 1]) {
+            k += 1;
+        } else {
+            basis.swap(k, k - 1);
+            (gs, mu) = gram_schmidt(basis);
+            k = (k - 1).max(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fnv1a;
+
+    #[test]
+    fn lattice() {
+        let solver = Solver::new::<u64>(None).unwrap();
+        assert_eq!(solver.basis.len(), solver.weights.len());
+        for v in solver.basis.iter() {
+            assert!(v.iter().all(|v| v.abs() < 256));
+            let dot = v.iter().zip(&solver.weights).fold(0u64, |acc, (&v, &w)| {
+                acc.wrapping_add((v as u64).wrapping_mul(w))
+            });
+            assert_eq!(dot, 0);
+        }
+    }
+
+    fn check_suffix<T>(state: T, target: T, alphabet: Option<&[u8]>)
+    where
+        T: Fnv + Into<u64> + PartialEq + core::fmt::Debug,
+        u8: AsPrimitive<T>,
+    {
+        let suffix = fnv1a_suffix(state, target, alphabet).unwrap();
+        if let Some(a) = alphabet {
+            assert!(suffix.iter().all(|c| a.contains(c)));
+        }
+        assert_eq!(state.fnv1a(&suffix), target);
+    }
+
+    #[test]
+    fn suffix() {
+        for alphabet in [None, Some(PRINTABLE), Some(ALPHANUMERIC)] {
+            check_suffix(u32::OFFSET_BASIS, 0, alphabet);
+            check_suffix(u32::OFFSET_BASIS.fnv1a(b"foo"), 0xdeadbeef, alphabet);
+        }
+        check_suffix(u64::OFFSET_BASIS.fnv1a(b"bar"), 0x0123_4567_89ab_cdef, None);
+    }
+
+    #[test]
+    fn suffix_64() {
+        for alphabet in [PRINTABLE, ALPHANUMERIC] {
+            check_suffix(
+                u64::OFFSET_BASIS.fnv1a(b"bar"),
+                0x0123_4567_89ab_cdef,
+                Some(alphabet),
+            );
+        }
+    }
+
+    #[test]
+    fn collisions() {
+        assert_eq!(fnv1a_suffix(0u32, 0, Some(b"a")), None);
+        assert_eq!(fnv1a_collisions(0u32, 2, Some(b"")), None);
+        assert_eq!(fnv1a_collisions(0u32, 0, None), Some(vec![]));
+        assert_eq!(fnv1a_collisions(0u32, 1, None), Some(vec![vec![]]));
+        check_collisions(None);
+    }
+
+    fn check_collisions(alphabet: Option<&[u8]>) {
+        let keys = fnv1a_collisions(u64::OFFSET_BASIS, 9, alphabet).unwrap();
+        let hash = fnv1a::<u64>(&keys[0]);
+        for (i, key) in keys.iter().enumerate() {
+            assert_eq!(fnv1a::<u64>(key), hash);
+            assert!(!keys[..i].contains(key));
+            if let Some(a) = alphabet {
+                assert!(key.iter().all(|c| a.contains(c)));
+            }
+        }
+    }
+
+    #[test]
+    fn collisions_alphabet() {
+        check_collisions(Some(PRINTABLE));
+        check_collisions(Some(b"0123456789abcdef"));
+    }
+}
@@ -0,0 +1,194 @@
+//! FNV prime search and validation
+//!
+//! The [FNV draft](https://datatracker.ietf.org/doc/draft-eastlake-fnv/21/) chooses the
+//! prime for an `n` bit hash as `p = 256^t + 2^8 + b` with `t = floor((5 + n) / 12)`
+//! and the smallest `b` such that:
+//!
+//! * `0 < b < 2^8`,
+//! * `b` has either 4 or 5 bits set,
+//! * `p mod (2^40 - 2^24 - 1) > 2^24 + 2^8 + 2^7`, and
+//! * `p` is prime.
+//!
+//! The draft only specifies power-of-two sizes. The same rules are applied to other sizes here.
+//! Since the arithmetic is done in `u128`, the size is limited to 128 bits.
+//! Below 31 bits there are no primes satisfying the modulus criterion.
+//!
+//! ```
+//! use yafnv::{fnv_prime, Fnv};
+//!
+//! assert_eq!(fnv_prime(64), Some(u64::PRIME as _));
+//! // A 48 bit prime
+//! assert_eq!(fnv_prime(48), Some(0x0001_0000_012d));
+//! ```
+
+/// Modulus of the FNV prime criterion `2^40 - 2^24 - 1`
+const MODULUS: u128 = (1 << 40) - (1 << 24) - 1;
+
+/// Lower bound of the FNV prime criterion `2^24 + 2^8 + 2^7`
+const BOUND: u128 = (1 << 24) + (1 << 8) + (1 << 7);
+
+/// The base `256^t + 2^8` of the `bits` wide FNV prime
+///
+/// Returns `None` if `bits` is not supported.
+fn base(bits: u32) -> Option<u128> {
+    let t = (5 + bits) / 12;
+    if !(1..=128).contains(&bits) || t == 0 || 8 * t >= bits {
+        None
+    } else {
+        Some((1 << (8 * t)) + (1 << 8))
+    }
+}
+
+/// Check whether `p` satisfies the FNV prime criteria for a `bits` wide hash.
+///
+/// This checks the form and primality but not that `b` is the smallest valid offset.
+///
+/// ```
+/// use yafnv::{is_fnv_prime, Fnv};
+///
+/// assert!(is_fnv_prime(32, u32::PRIME as _));
+/// assert!(is_fnv_prime(128, u128::PRIME));
+/// assert!(!is_fnv_prime(32, u64::PRIME as _));
+/// ```
+pub fn is_fnv_prime(bits: u32, p: u128) -> bool {
+    let Some(base) = base(bits) else {
+        return false;
+    };
+    let Some(b) = p.checked_sub(base) else {
+        return false;
+    };
+    0 < b && b < 1 << 8 && matches!(b.count_ones(), 4 | 5) && p % MODULUS > BOUND && is_prime(p)
+}
+
+/// Iterate over all primes satisfying the FNV criteria for a `bits` wide hash.
+///
+/// The primes are in ascending order. The first one is the FNV prime.
+pub fn fnv_primes(bits: u32) -> impl Iterator<Item = u128> {
+    let base = base(bits);
+    (1..1 << 8)
+        .filter_map(move |b| base.map(|base| base + b))
+        .filter(move |&p| is_fnv_prime(bits, p))
+}
+
+/// Search the FNV prime for a `bits` wide hash.
+///
+/// Returns `None` if there is none.
+pub fn fnv_prime(bits: u32) -> Option<u128> {
+    fnv_primes(bits).next()
+}
+
+/// `a + b mod m` for `a, b < m`
+fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// `a * b mod m` for `a, b < m`
+fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    if let (Ok(a), Ok(b)) = (u64::try_from(a), u64::try_from(b)) {
+        return (a as u128 * b as u128) % m;
+    }
+    let mut r = 0;
+    for i in (0..128 - b.leading_zeros()).rev() {
+        r = add_mod(r, r, m);
+        if (b >> i) & 1 != 0 {
+            r = add_mod(r, a, m);
+        }
+    }
+    r
+}
+
+/// `a^e mod m` for `a < m`
+fn pow_mod(mut a: u128, mut e: u128, m: u128) -> u128 {
+    let mut r = 1 % m;
+    while e != 0 {
+        if e & 1 != 0 {
+            r = mul_mod(r, a, m);
+        }
+        a = mul_mod(a, a, m);
+        e >>= 1;
+    }
+    r
+}
+
+/// Check `n` for primality using Miller-Rabin.
+///
+/// The first twenty primes are used as bases. The result is exact below
+/// `3.3e24` (about `2^81`), where these bases are known to be sufficient.
+/// Above, it is heuristic: no composite passing all of these bases is known,
+/// but none is ruled out either.
+pub fn is_prime(n: u128) -> bool {
+    const BASES: [u128; 20] = [
+        2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71,
+    ];
+    if n < 2 {
+        return false;
+    }
+    for p in BASES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    'base: for a in BASES {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'base;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Fnv;
+
+    #[test]
+    fn primes() {
+        assert!(!is_prime(0));
+        assert!(!is_prime(1));
+        assert!(is_prime(2));
+        assert!(is_prime(71));
+        assert!(!is_prime(73 * 79));
+        assert!(is_prime(u64::MAX as u128 - 58)); // 2^64 - 59
+        assert!(!is_prime(u64::MAX as u128));
+        assert!(is_prime(u128::MAX - 158)); // 2^128 - 159
+        assert!(!is_prime(u128::MAX - 156));
+        // Carmichael numbers
+        assert!(!is_prime(561));
+        assert!(!is_prime(3_215_031_751));
+    }
+
+    #[test]
+    fn crate_primes() {
+        assert_eq!(fnv_prime(32), Some(u32::PRIME as _));
+        assert_eq!(fnv_prime(64), Some(u64::PRIME as _));
+        assert_eq!(fnv_prime(128), Some(u128::PRIME));
+    }
+
+    #[test]
+    fn criteria() {
+        assert_eq!(fnv_prime(16), None);
+        assert_eq!(fnv_prime(0), None);
+        assert_eq!(fnv_prime(129), None);
+        assert!(!is_fnv_prime(32, 0));
+        assert!(!is_fnv_prime(32, u128::MAX));
+        let p = fnv_prime(48).unwrap();
+        assert!(is_fnv_prime(48, p));
+        assert!(p < 1 << 48);
+        assert!(fnv_primes(64).all(|p| is_fnv_prime(64, p)));
+        assert!(fnv_primes(64).count() > 1);
+    }
+}
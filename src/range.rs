@@ -0,0 +1,120 @@
+//! Mapping hashes to arbitrary ranges
+//!
+//! To map a hash to `0..range` where `range` is not a power of two,
+//! the [FNV draft](https://datatracker.ietf.org/doc/draft-eastlake-fnv/21/) describes
+//! the "lazy mod mapping" and the "retry method".
+//! Multiplication and shift is a fast alternative to the lazy mod mapping.
+
+use num_traits::{AsPrimitive, PrimInt, WrappingAdd};
+
+use crate::Fnv;
+
+/// Map a hash to `0..range` using the lazy mod mapping.
+///
+/// This is `hash % range`. It is biased: with a `w` bit hash, the values below
+/// `2^w % range` are returned one more time (out of `2^w / range`) than the others.
+/// The relative bias is at most `range / 2^w` and negligible if the hash is much
+/// wider than `range`.
+///
+/// # Panics
+/// If `range` is zero.
+#[inline]
+pub fn lazy_mod<T: PrimInt>(hash: T, range: T) -> T {
+    hash % range
+}
+
+/// Map a hash to `0..range` using the retry method.
+///
+/// While `hash` is at least the largest multiple of `range` not exceeding `T::MAX`,
+/// it is rehashed as `hash * PRIME + OFFSET_BASIS`. The result is then reduced modulo
+/// `range`.
+///
+/// This is unbiased. The expected number of retries is less than one.
+/// A power of two `range` is a mask and needs no retries.
+///
+/// ```
+/// use yafnv::{fnv1a, retry_mod};
+///
+/// let bucket = retry_mod(fnv1a::<u32>(b"foobar"), 50000);
+/// assert_eq!(bucket, 35720);
+/// ```
+///
+/// # Panics
+/// If `range` is zero.
+#[inline]
+pub fn retry_mod<T>(mut hash: T, range: T) -> T
+where
+    T: Fnv + PrimInt + WrappingAdd,
+    u8: AsPrimitive<T>,
+{
+    if range.count_ones() == 1 {
+        return hash & (range - T::one());
+    }
+    let retry_level = (T::max_value() / range) * range;
+    while hash >= retry_level {
+        hash = hash.wrapping_mul(&T::PRIME).wrapping_add(&T::OFFSET_BASIS);
+    }
+    hash % range
+}
+
+/// Map a 32 bit hash to `0..range` by multiplication and shift.
+///
+/// This is `(hash * range) >> 32`. It has the same bias as [`lazy_mod()`] but avoids
+/// the division and uses the better mixed high bits of the hash.
+#[inline]
+pub const fn mul_shift_32(hash: u32, range: u32) -> u32 {
+    ((hash as u64 * range as u64) >> 32) as _
+}
+
+/// Map a 64 bit hash to `0..range` by multiplication and shift.
+///
+/// This is `(hash * range) >> 64`. It has the same bias as [`lazy_mod()`] but avoids
+/// the division and uses the better mixed high bits of the hash.
+#[inline]
+pub const fn mul_shift_64(hash: u64, range: u64) -> u64 {
+    ((hash as u128 * range as u128) >> 64) as _
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fnv1a;
+
+    #[test]
+    fn reference() {
+        // Regression values of this implementation, `range` as TRUE_HASH_SIZE
+        // in the retry method example of the FNV draft
+        let range = 50000;
+        let h = fnv1a::<u32>(b"foobar");
+        assert_eq!(lazy_mod(h, range), 35720);
+        assert_eq!(retry_mod(h, range), 35720);
+        assert_eq!(mul_shift_32(h, range), 37424);
+        // RETRY_LEVEL
+        assert_eq!((u32::MAX / range) * range, 4294950000);
+        assert_eq!(lazy_mod(4294950000, range), 0);
+        assert_eq!(retry_mod(4294950000, range), 14165);
+        assert_eq!(retry_mod(u32::MAX, range), 8642);
+        assert_eq!(mul_shift_32(u32::MAX, range), range - 1);
+        assert_eq!(mul_shift_64(u64::MAX, 3), 2);
+        assert_eq!(mul_shift_64(0, 3), 0);
+    }
+
+    #[test]
+    fn unbiased() {
+        // Complete blocks of `range` values below the retry level are mapped uniformly,
+        // values above it are rehashed.
+        let range = 7u32;
+        let mut counts = [0; 7];
+        let level = (u32::MAX / range) * range;
+        for h in (0..63).chain(level - 63..level) {
+            counts[retry_mod(h, range) as usize] += 1;
+        }
+        assert!(counts.iter().all(|&c| c == counts[0]));
+        for h in level..=u32::MAX {
+            assert!(retry_mod(h, range) < range);
+        }
+        // Powers of two are masked
+        assert_eq!(retry_mod(u32::MAX, 1 << 16), 0xffff);
+        assert_eq!(retry_mod(u64::MAX, 1), 0);
+    }
+}
@@ -0,0 +1,652 @@
+//! [`serde`](https://docs.rs/serde) serializer into an FNV state
+//!
+//! The serialized form of a value is fed to the FNV state as it is produced.
+//! There is no intermediate buffer and no allocation. See [`FnvSerializer`] for the encoding.
+
+use core::fmt;
+use core::marker::PhantomData;
+use num_traits::AsPrimitive;
+use serde::ser::{
+    self, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+    SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+};
+
+use crate::{Fnv, Fnv1a, FnvHash, Variant};
+
+/// Error returned by the [`Serialize`] implementation of the value
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Error;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("serialization failed")
+    }
+}
+
+impl ser::StdError for Error {}
+
+impl ser::Error for Error {
+    fn custom<M: fmt::Display>(_msg: M) -> Self {
+        Self
+    }
+}
+
+/// A [`serde::Serializer`] that hashes the serialized form of a value
+///
+/// The encoding is deterministic and frozen: it will not change without a major version bump.
+/// Scalars use the [`FnvHash`] encoding. All lengths and tags are written *after* the data
+/// they describe. This allows streaming sequences and maps of unknown length
+/// and keeps the encoding uniquely decodable (from the end).
+///
+/// * `bool`, integers, `char`, and floats: as [`FnvHash`]
+/// * `str` and bytes: the bytes followed by the length as `u64`
+/// * `None`: `0u8`, `Some`: the value followed by `1u8`
+/// * Unit, unit structs: nothing
+/// * Newtype structs: the inner value
+/// * Tuples and tuple structs: the elements in order
+/// * Structs: for each serialized field the value followed by the field name as a
+///   `str`, then the number of serialized fields as `u64`.
+///   Field names are included since fields may be skipped.
+/// * Sequences: the elements followed by the number of elements as `u64`
+/// * Maps: the keys and values in iteration order followed by the number of entries as `u64`
+/// * Enum variants: the variant content as above followed by the variant index as `u32`
+///
+/// The hash of a map depends on its iteration order. Use ordered maps (e.g. `BTreeMap`)
+/// for reproducible fingerprints.
+///
+/// ```
+/// use serde::Serialize;
+/// use yafnv::{Fnv1, FnvSerializer};
+///
+/// let mut s = FnvSerializer::<u32, Fnv1>::default();
+/// (1u8, "foo").serialize(&mut s).unwrap();
+/// assert_eq!(s.finish(), yafnv::fnv1::<u32>(&[1, b'f', b'o', b'o', 3, 0, 0, 0, 0, 0, 0, 0]));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct FnvSerializer<T, V = Fnv1a> {
+    state: T,
+    variant: PhantomData<V>,
+}
+
+impl<T, V> FnvSerializer<T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    /// Create a serializer starting with a state corresponding to the hash `key`.
+    #[inline]
+    pub fn with_key(key: T) -> Self {
+        Self {
+            state: key,
+            variant: PhantomData,
+        }
+    }
+
+    /// Return the hash of the values serialized so far.
+    #[inline]
+    pub fn finish(&self) -> T {
+        self.state
+    }
+
+    #[inline]
+    fn hash(&mut self, value: &(impl FnvHash + ?Sized)) {
+        self.state = value.fnv_hash::<T, V>(self.state);
+    }
+
+    #[inline]
+    fn bytes(&mut self, data: &[u8]) {
+        self.state = V::update(self.state, data);
+    }
+
+    #[inline]
+    fn len(&mut self, len: usize) {
+        self.hash(&(len as u64));
+    }
+}
+
+impl<T, V> Default for FnvSerializer<T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::with_key(V::offset_basis())
+    }
+}
+
+/// Hash the serialized form of a value with FNV-1a.
+///
+/// See [`FnvSerializer`] for other variants and the encoding.
+///
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Settings {
+///     rate: u32,
+///     name: &'static str,
+/// }
+///
+/// let a = yafnv::hash_serialize::<u64, _>(&Settings { rate: 1000, name: "adc" }).unwrap();
+/// let b = yafnv::hash_serialize::<u64, _>(&Settings { rate: 1001, name: "adc" }).unwrap();
+/// assert_ne!(a, b);
+/// ```
+pub fn hash_serialize<T, S>(value: &S) -> Result<T, Error>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    S: Serialize + ?Sized,
+{
+    let mut serializer = FnvSerializer::<T, Fnv1a>::default();
+    value.serialize(&mut serializer)?;
+    Ok(serializer.finish())
+}
+
+/// State for compound values
+#[doc(hidden)]
+pub struct Compound<'a, T, V> {
+    ser: &'a mut FnvSerializer<T, V>,
+    len: usize,
+    variant: Option<u32>,
+}
+
+impl<T, V> Compound<'_, T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    #[inline]
+    fn element<S: Serialize + ?Sized>(&mut self, value: &S) -> Result<(), Error> {
+        self.len += 1;
+        value.serialize(&mut *self.ser)
+    }
+
+    #[inline]
+    fn field<S: Serialize + ?Sized>(&mut self, key: &str, value: &S) -> Result<(), Error> {
+        self.element(value)?;
+        key.serialize(&mut *self.ser)
+    }
+
+    #[inline]
+    fn end_len(self) -> Result<(), Error> {
+        self.ser.len(self.len);
+        self.end()
+    }
+
+    #[inline]
+    fn end(self) -> Result<(), Error> {
+        if let Some(index) = self.variant {
+            self.ser.hash(&index);
+        }
+        Ok(())
+    }
+}
+
+/// Adapter hashing formatted strings
+struct Collect<'a, T, V> {
+    ser: &'a mut FnvSerializer<T, V>,
+    len: usize,
+}
+
+impl<T, V> fmt::Write for Collect<'_, T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.len += s.len();
+        self.ser.bytes(s.as_bytes());
+        Ok(())
+    }
+}
+
+impl<'a, T, V> ser::Serializer for &'a mut FnvSerializer<T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Compound<'a, T, V>;
+    type SerializeTuple = Compound<'a, T, V>;
+    type SerializeTupleStruct = Compound<'a, T, V>;
+    type SerializeTupleVariant = Compound<'a, T, V>;
+    type SerializeMap = Compound<'a, T, V>;
+    type SerializeStruct = Compound<'a, T, V>;
+    type SerializeStructVariant = Compound<'a, T, V>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), Error> {
+        self.hash(&v);
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), Error> {
+        self.hash(&v);
+        Ok(())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), Error> {
+        self.hash(&v);
+        Ok(())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), Error> {
+        self.hash(&v);
+        Ok(())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), Error> {
+        self.hash(&v);
+        Ok(())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<(), Error> {
+        self.hash(&v);
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), Error> {
+        self.hash(&v);
+        Ok(())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), Error> {
+        self.hash(&v);
+        Ok(())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), Error> {
+        self.hash(&v);
+        Ok(())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), Error> {
+        self.hash(&v);
+        Ok(())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<(), Error> {
+        self.hash(&v);
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), Error> {
+        self.hash(&v);
+        Ok(())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), Error> {
+        self.hash(&v);
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), Error> {
+        self.hash(&v);
+        Ok(())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), Error> {
+        self.serialize_bytes(v.as_bytes())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+        self.bytes(v);
+        self.len(v.len());
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), Error> {
+        self.hash(&0u8);
+        Ok(())
+    }
+
+    fn serialize_some<S: Serialize + ?Sized>(self, value: &S) -> Result<(), Error> {
+        value.serialize(&mut *self)?;
+        self.hash(&1u8);
+        Ok(())
+    }
+
+    fn serialize_unit(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<(), Error> {
+        self.hash(&variant_index);
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<S: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &S,
+    ) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<S: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &S,
+    ) -> Result<(), Error> {
+        value.serialize(&mut *self)?;
+        self.hash(&variant_index);
+        Ok(())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Compound<'a, T, V>, Error> {
+        Ok(Compound {
+            ser: self,
+            len: 0,
+            variant: None,
+        })
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Compound<'a, T, V>, Error> {
+        self.serialize_seq(None)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a, T, V>, Error> {
+        self.serialize_seq(None)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a, T, V>, Error> {
+        Ok(Compound {
+            ser: self,
+            len: 0,
+            variant: Some(variant_index),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Compound<'a, T, V>, Error> {
+        self.serialize_seq(None)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a, T, V>, Error> {
+        self.serialize_seq(None)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Compound<'a, T, V>, Error> {
+        self.serialize_tuple_variant(name, variant_index, variant, len)
+    }
+
+    fn collect_str<S: fmt::Display + ?Sized>(self, value: &S) -> Result<(), Error> {
+        let mut collect = Collect { ser: self, len: 0 };
+        fmt::write(&mut collect, format_args!("{}", value)).or(Err(Error))?;
+        let len = collect.len;
+        self.len(len);
+        Ok(())
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+impl<T, V> SerializeSeq for Compound<'_, T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<S: Serialize + ?Sized>(&mut self, value: &S) -> Result<(), Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        self.end_len()
+    }
+}
+
+impl<T, V> SerializeTuple for Compound<'_, T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<S: Serialize + ?Sized>(&mut self, value: &S) -> Result<(), Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl<T, V> SerializeTupleStruct for Compound<'_, T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<S: Serialize + ?Sized>(&mut self, value: &S) -> Result<(), Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl<T, V> SerializeTupleVariant for Compound<'_, T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<S: Serialize + ?Sized>(&mut self, value: &S) -> Result<(), Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl<T, V> SerializeMap for Compound<'_, T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<S: Serialize + ?Sized>(&mut self, key: &S) -> Result<(), Error> {
+        self.element(key)
+    }
+
+    fn serialize_value<S: Serialize + ?Sized>(&mut self, value: &S) -> Result<(), Error> {
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        self.end_len()
+    }
+}
+
+impl<T, V> SerializeStruct for Compound<'_, T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<S: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &S,
+    ) -> Result<(), Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        self.end_len()
+    }
+}
+
+impl<T, V> SerializeStructVariant for Compound<'_, T, V>
+where
+    T: Fnv,
+    u8: AsPrimitive<T>,
+    V: Variant,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<S: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &S,
+    ) -> Result<(), Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        self.end_len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fnv1a;
+
+    fn hash<S: Serialize + ?Sized>(value: &S) -> u64 {
+        hash_serialize::<u64, _>(value).unwrap()
+    }
+
+    struct Display;
+
+    impl fmt::Display for Display {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("f")?;
+            f.write_str("oo")
+        }
+    }
+
+    impl Serialize for Display {
+        fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(self)
+        }
+    }
+
+    struct Fail;
+
+    impl Serialize for Fail {
+        fn serialize<S: ser::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+            Err(ser::Error::custom("fail"))
+        }
+    }
+
+    #[test]
+    fn encoding() {
+        assert_eq!(hash(&true), fnv1a::<u64>(&[1]));
+        assert_eq!(hash(&0x1234u16), fnv1a::<u64>(&[0x34, 0x12]));
+        assert_eq!(hash(&-0.0f32), hash(&0u32));
+        assert_eq!(
+            hash("ab"),
+            fnv1a::<u64>(&[b'a', b'b', 2, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(hash(&Display), hash("foo"));
+        assert_eq!(hash(&None::<u8>), fnv1a::<u64>(&[0]));
+        assert_eq!(hash(&Some(5u8)), fnv1a::<u64>(&[5, 1]));
+        assert_eq!(hash(&()), fnv1a::<u64>(&[]));
+        assert_eq!(hash(&(1u8, 2u8)), fnv1a::<u64>(&[1, 2]));
+        assert_eq!(hash(&[1u8, 2][..]), hash(&(1u8, 2u8, 2u64)));
+        assert_eq!(hash(&Ok::<u8, u8>(5)), hash(&(5u8, 0u32)));
+        assert_eq!(hash(&Err::<u8, u8>(5)), hash(&(5u8, 1u32)));
+        assert_eq!(hash_serialize::<u32, _>(&Fail), Err(Error));
+    }
+
+    #[test]
+    fn derive() {
+        #[derive(::serde::Serialize)]
+        struct Struct {
+            a: u8,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            b: Option<u8>,
+        }
+
+        #[derive(::serde::Serialize)]
+        enum Enum {
+            A,
+            B { c: u8 },
+        }
+
+        assert_eq!(hash(&Struct { a: 1, b: None }), hash(&(1u8, "a", 1u64)));
+        assert_eq!(
+            hash(&Struct { a: 1, b: Some(2) }),
+            hash(&(1u8, "a", Some(2u8), "b", 2u64))
+        );
+        assert_eq!(hash(&Enum::A), hash(&0u32));
+        assert_eq!(hash(&Enum::B { c: 3 }), hash(&(3u8, "c", 1u64, 1u32)));
+    }
+
+    #[test]
+    fn variant() {
+        let mut s = FnvSerializer::<u32, crate::Fnv1>::default();
+        5u8.serialize(&mut s).unwrap();
+        assert_eq!(s.finish(), crate::fnv1::<u32>(&[5]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn map() {
+        use std::collections::BTreeMap;
+
+        let m = BTreeMap::from([(1u8, 2u8), (3, 4)]);
+        assert_eq!(hash(&m), hash(&(1u8, 2u8, 3u8, 4u8, 2u64)));
+    }
+}
@@ -0,0 +1,641 @@
+//! FNV test vectors
+//!
+//! FNV-1 and FNV-1a test vectors for all supported hash sizes. The vectors can be used to
+//! verify other [`Fnv`] implementations and ports with [`check()`] and [`check_fnv()`].
+//!
+//! The 37 inputs per table are a subset of the inputs of the reference implementation
+//! test suite (`test_fnv.c`, see also the
+//! [FNV draft](https://datatracker.ietf.org/doc/draft-eastlake-fnv/21/)):
+//! the empty input, the prefixes of `"foobar"` and the single letters `a` to `f`, each also
+//! with a trailing NUL, the prefixes of `"chongo"`, and a selection of longer text and binary
+//! inputs. The repeated inputs (`R10`, `R500`) and the remaining URLs of the suite are not
+//! included.
+//!
+//! ```
+//! use yafnv::{fnv1a_64, test_vectors};
+//!
+//! test_vectors::check(test_vectors::FNV1A_64, fnv1a_64).unwrap();
+//! ```
+
+use num_traits::AsPrimitive;
+
+use crate::{Fnv, U1024, U256, U512};
+
+/// A test vector mismatch
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch<T> {
+    /// Index of the test vector
+    pub index: usize,
+    /// Input data
+    pub input: &'static [u8],
+    /// Expected hash
+    pub expected: T,
+    /// Computed hash
+    pub actual: T,
+}
+
+/// Verify a hash function against test vectors.
+///
+/// Returns the first mismatch.
+pub fn check<T, F>(vectors: &[(&'static [u8], T)], mut hash: F) -> Result<(), Mismatch<T>>
+where
+    T: Copy + PartialEq,
+    F: FnMut(&[u8]) -> T,
+{
+    for (index, &(input, expected)) in vectors.iter().enumerate() {
+        let actual = hash(input);
+        if actual != expected {
+            return Err(Mismatch {
+                index,
+                input,
+                expected,
+                actual,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Verify an [`Fnv`] implementation against FNV-1 and FNV-1a test vectors.
+///
+/// ```
+/// use yafnv::test_vectors::{check_fnv, FNV1A_128, FNV1_128};
+///
+/// check_fnv(FNV1_128, FNV1A_128).unwrap();
+/// ```
+pub fn check_fnv<T>(
+    fnv1: &[(&'static [u8], T)],
+    fnv1a: &[(&'static [u8], T)],
+) -> Result<(), Mismatch<T>>
+where
+    T: Fnv + PartialEq,
+    u8: AsPrimitive<T>,
+{
+    check(fnv1, |data| T::OFFSET_BASIS.fnv1(data))?;
+    check(fnv1a, |data| T::OFFSET_BASIS.fnv1a(data))
+}
+
+/// FNV-1 32 bit test vectors
+#[rustfmt::skip]
+pub const FNV1_32: &[(&[u8], u32)] = &[
+    (b"", 0x811c9dc5),
+    (b"a", 0x050c5d7e),
+    (b"b", 0x050c5d7d),
+    (b"c", 0x050c5d7c),
+    (b"d", 0x050c5d7b),
+    (b"e", 0x050c5d7a),
+    (b"f", 0x050c5d79),
+    (b"fo", 0x6b772514),
+    (b"foo", 0x408f5e13),
+    (b"foob", 0xb4b1178b),
+    (b"fooba", 0xfdc80fb0),
+    (b"foobar", 0x31f0b262),
+    (b"\x00", 0x050c5d1f),
+    (b"a\x00", 0x70772d5a),
+    (b"b\x00", 0x6f772bc7),
+    (b"c\x00", 0x6e772a34),
+    (b"d\x00", 0x6d7728a1),
+    (b"e\x00", 0x6c77270e),
+    (b"f\x00", 0x6b77257b),
+    (b"fo\x00", 0x408f5e7c),
+    (b"foo\x00", 0xb4b117e9),
+    (b"foob\x00", 0xfdc80fd1),
+    (b"fooba\x00", 0x31f0b210),
+    (b"foobar\x00", 0xffe8d046),
+    (b"ch", 0x6e772a5c),
+    (b"cho", 0x4197aebb),
+    (b"chon", 0xfcc8100f),
+    (b"chong", 0xfdf147fa),
+    (b"chongo", 0xbcd44ee1),
+    (b"chongo was here!\n", 0xdd002f35),
+    (b"127.0.0.1", 0x0a3cffd8),
+    (b"\xff\x00\x00\x01", 0xb78320a1),
+    (b"\x01\x00\x00\xff", 0x0caf4135),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x00", 0x9be17165),
+    (b"line 1\nline 2\nline 3", 0x31ae8f83),
+    (b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", 0x2aa7d593),
+    (b"chongo <Landon Curt Noll> /\\../\\", 0x995fa9c4),
+];
+
+/// FNV-1 64 bit test vectors
+#[rustfmt::skip]
+pub const FNV1_64: &[(&[u8], u64)] = &[
+    (b"", 0xcbf29ce484222325),
+    (b"a", 0xaf63bd4c8601b7be),
+    (b"b", 0xaf63bd4c8601b7bd),
+    (b"c", 0xaf63bd4c8601b7bc),
+    (b"d", 0xaf63bd4c8601b7bb),
+    (b"e", 0xaf63bd4c8601b7ba),
+    (b"f", 0xaf63bd4c8601b7b9),
+    (b"fo", 0x08326207b4eb2f34),
+    (b"foo", 0xd8cbc7186ba13533),
+    (b"foob", 0x0378817ee2ed65cb),
+    (b"fooba", 0xd329d59b9963f790),
+    (b"foobar", 0x340d8765a4dda9c2),
+    (b"\x00", 0xaf63bd4c8601b7df),
+    (b"a\x00", 0x08326707b4eb37da),
+    (b"b\x00", 0x08326607b4eb3627),
+    (b"c\x00", 0x08326507b4eb3474),
+    (b"d\x00", 0x08326407b4eb32c1),
+    (b"e\x00", 0x08326307b4eb310e),
+    (b"f\x00", 0x08326207b4eb2f5b),
+    (b"fo\x00", 0xd8cbc7186ba1355c),
+    (b"foo\x00", 0x0378817ee2ed65a9),
+    (b"foob\x00", 0xd329d59b9963f7f1),
+    (b"fooba\x00", 0x340d8765a4dda9b0),
+    (b"foobar\x00", 0x50a6d3b724a774a6),
+    (b"ch", 0x08326507b4eb341c),
+    (b"cho", 0xd8d5c8186ba98bfb),
+    (b"chon", 0x1ccefc7ef118dbef),
+    (b"chong", 0x0c92fab3ad3db77a),
+    (b"chongo", 0x9b77794f5fdec421),
+    (b"chongo was here!\n", 0xe0aca20b624e4235),
+    (b"127.0.0.1", 0x34ad3b1041204318),
+    (b"\xff\x00\x00\x01", 0xd6b2b17bf4b71261),
+    (b"\x01\x00\x00\xff", 0x447bfb7f98e615b5),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x00", 0xa8c7f832281a39c5),
+    (b"line 1\nline 2\nline 3", 0xa64e5f36c9e2b0e3),
+    (b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", 0x92a3d1cd078ba293),
+    (b"chongo <Landon Curt Noll> /\\../\\", 0x8fd0680da3088a04),
+];
+
+/// FNV-1 128 bit test vectors
+#[rustfmt::skip]
+pub const FNV1_128: &[(&[u8], u128)] = &[
+    (b"", 0x6c62272e07bb014262b821756295c58d),
+    (b"a", 0xd228cb69101a8caf78912b704e4a141e),
+    (b"b", 0xd228cb69101a8caf78912b704e4a141d),
+    (b"c", 0xd228cb69101a8caf78912b704e4a141c),
+    (b"d", 0xd228cb69101a8caf78912b704e4a141b),
+    (b"e", 0xd228cb69101a8caf78912b704e4a141a),
+    (b"f", 0xd228cb69101a8caf78912b704e4a1419),
+    (b"fo", 0x0880945ae9ab1be95aa073305526baac),
+    (b"foo", 0xa68bb298318b5822836dbc78c6a7b1cb),
+    (b"foob", 0x66ab68f6c1757277b806e89c7057c4ab),
+    (b"fooba", 0xf15a7f64b683d94f7080387e3bfefe08),
+    (b"foobar", 0x7896bfea9c3c64bf6dc58353d2c293aa),
+    (b"\x00", 0xd228cb69101a8caf78912b704e4a147f),
+    (b"a\x00", 0x0880945aeeab1be95aa073305526c0ea),
+    (b"b\x00", 0x0880945aedab1be95aa073305526bfaf),
+    (b"c\x00", 0x0880945aecab1be95aa073305526be74),
+    (b"d\x00", 0x0880945aebab1be95aa073305526bd39),
+    (b"e\x00", 0x0880945aeaab1be95aa073305526bbfe),
+    (b"f\x00", 0x0880945ae9ab1be95aa073305526bac3),
+    (b"fo\x00", 0xa68bb298318b5822836dbc78c6a7b1a4),
+    (b"foo\x00", 0x66ab68f6c1757277b806e89c7057c4c9),
+    (b"foob\x00", 0xf15a7f64b683d94f7080387e3bfefe69),
+    (b"fooba\x00", 0x7896bfea9c3c64bf6dc58353d2c293d8),
+    (b"foobar\x00", 0xb550e841e84ff78c12089824556bb22e),
+    (b"ch", 0x0880945aecab1be95aa073305526be1c),
+    (b"cho", 0xa68bb29f528b5822836dbc78c6abec1b),
+    (b"chon", 0x66ab75f6ac757277b806e89c758b8557),
+    (b"chong", 0xf16fb20b8b83d94f70803884a2ad126a),
+    (b"chongo", 0x9912c147153c64bf6dc58b342af5a801),
+    (b"chongo was here!\n", 0x40ab469af9cf0fe57236785215beee65),
+    (b"127.0.0.1", 0x7c4e6a711003b30fd4229f1c4e8930b8),
+    (b"\xff\x00\x00\x01", 0x66ad3d06e1757277b806e89d305ddd81),
+    (b"\x01\x00\x00\xff", 0x66ad33f14b757277b806e89d2ca40205),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x00", 0x9d30c1f78465995be47dda5e4e4e77ed),
+    (b"line 1\nline 2\nline 3", 0xbb3cb6762492b145364b3f4c2c47a3cb),
+    (b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", 0xe5fbdf17b692e5f507af6a81b3c9e6f3),
+    (b"chongo <Landon Curt Noll> /\\../\\", 0xf07e80295b7ec5ed254a4c20bfbeab04),
+];
+
+/// FNV-1 256 bit test vectors
+#[rustfmt::skip]
+pub const FNV1_256: &[(&[u8], U256)] = &[
+    (b"", U256::from_be_limbs([0xdd268dbcaac55036, 0x2d98c384c4e576cc, 0xc8b1536847b6bbb3, 0x1023b4c8caee0535])),
+    (b"a", U256::from_be_limbs([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811381e])),
+    (b"b", U256::from_be_limbs([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811381d])),
+    (b"c", U256::from_be_limbs([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811381c])),
+    (b"d", U256::from_be_limbs([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811381b])),
+    (b"e", U256::from_be_limbs([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811381a])),
+    (b"f", U256::from_be_limbs([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b27168113819])),
+    (b"fo", U256::from_be_limbs([0xf4f7a1c2efd0e1e4, 0xbac3834525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0cac4])),
+    (b"foo", U256::from_be_limbs([0x8b0e658c2f1c837e, 0xdde9cce359de3a17, 0x84bd1d30340f770b, 0xe97fd657c4b92da3])),
+    (b"foob", U256::from_be_limbs([0xe46ddd4ed460b0b2, 0x7464c2459f2a8e9d, 0x123f79d831721584, 0xcc463bb5ccca496b])),
+    (b"fooba", U256::from_be_limbs([0x366f691cc850bd44, 0x3202d18bb803c3d0, 0x4e05f6cc9133d727, 0x4564cd1afc83cf00])),
+    (b"foobar", U256::from_be_limbs([0xb055ea2f2cc3908d, 0xddb794c02d3889dc, 0x32453dad5ae35b75, 0x3ac86c6c2ac80d72])),
+    (b"\x00", U256::from_be_limbs([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811387f])),
+    (b"a\x00", U256::from_be_limbs([0xf4f7a1c2efd0e1e4, 0xbac3884525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0d19a])),
+    (b"b\x00", U256::from_be_limbs([0xf4f7a1c2efd0e1e4, 0xbac3874525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0d037])),
+    (b"c\x00", U256::from_be_limbs([0xf4f7a1c2efd0e1e4, 0xbac3864525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0ced4])),
+    (b"d\x00", U256::from_be_limbs([0xf4f7a1c2efd0e1e4, 0xbac3854525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0cd71])),
+    (b"e\x00", U256::from_be_limbs([0xf4f7a1c2efd0e1e4, 0xbac3844525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0cc0e])),
+    (b"f\x00", U256::from_be_limbs([0xf4f7a1c2efd0e1e4, 0xbac3834525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0caab])),
+    (b"fo\x00", U256::from_be_limbs([0x8b0e658c2f1c837e, 0xdde9cce359de3a17, 0x84bd1d30340f770b, 0xe97fd657c4b92dcc])),
+    (b"foo\x00", U256::from_be_limbs([0xe46ddd4ed460b0b2, 0x7464c2459f2a8e9d, 0x123f79d831721584, 0xcc463bb5ccca4909])),
+    (b"foob\x00", U256::from_be_limbs([0x366f691cc850bd44, 0x3202d18bb803c3d0, 0x4e05f6cc9133d727, 0x4564cd1afc83cf61])),
+    (b"fooba\x00", U256::from_be_limbs([0xb055ea2f2cc3908d, 0xddb794c02d3889dc, 0x32453dad5ae35b75, 0x3ac86c6c2ac80d00])),
+    (b"foobar\x00", U256::from_be_limbs([0x6a7f34a5db9de0e5, 0x3da0b87eb5672c59, 0xb60487650947d390, 0x83ee59ff536aa516])),
+    (b"ch", U256::from_be_limbs([0xf4f7a1c2efd0e1e4, 0xbac3864525c0721a, 0x06dd328fa3d7a914, 0x39a073434fe0cebc])),
+    (b"cho", U256::from_be_limbs([0x8b0e658c2f1c837e, 0xddf1ede359de3a17, 0x84bd1d30340f770b, 0xe97fd657c4beaedb])),
+    (b"chon", U256::from_be_limbs([0xe46ddd4ed460b0b2, 0x852bbd459f2a8e9d, 0x123f79d831721584, 0xcc463bb5d46c79df])),
+    (b"chong", U256::from_be_limbs([0x366f691cc850bd63, 0x1821568bb803c3d0, 0x4e05f6cc9133d727, 0x4564cd25926d005a])),
+    (b"chongo", U256::from_be_limbs([0xb055ea2f2cc3c5fc, 0xe33b5dc02d3889dc, 0x32453dad5ae35b75, 0x3ac87b1a0d277ca1])),
+    (b"chongo was here!\n", U256::from_be_limbs([0x49fd0b4f53c93acf, 0x999e9af97695e0fb, 0x916e27677837f9b4, 0x001a4b9b965f3815])),
+    (b"127.0.0.1", U256::from_be_limbs([0x0c37a392d3cd62b1, 0xa81755c403a80f65, 0x9b80c9a5b0151496, 0xcc53bc38c5893258])),
+    (b"\xff\x00\x00\x01", U256::from_be_limbs([0xe46ddd4ed460b0b4, 0xc653b2459f2a8e9d, 0x123f79d831721584, 0xcc463bb6df448581])),
+    (b"\x01\x00\x00\xff", U256::from_be_limbs([0xe46ddd4ed460b0b4, 0xbac9fc459f2a8e9d, 0x123f79d831721584, 0xcc463bb6d9ef33f5])),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x00", U256::from_be_limbs([0xf63992974c8a9714, 0x7e5fd9b58e128067, 0x6847c11bde9a616a, 0xe0a6f17100e33055])),
+    (b"line 1\nline 2\nline 3", U256::from_be_limbs([0x5eacd1924a459656, 0x5e2824ff04fb6313, 0xbe6633cb43e3c951, 0x86aac773468aee13])),
+    (b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", U256::from_be_limbs([0xc8674169dba4435b, 0xb8ac7cc517cc5276, 0x4eb0aef19ec61c71, 0x73ec4b6f2a9af8b3])),
+    (b"chongo <Landon Curt Noll> /\\../\\", U256::from_be_limbs([0xbe08cda508edcbe7, 0x7284caaa0e6f6761, 0x03af865cf2c035bd, 0x55991613a70ef604])),
+];
+
+/// FNV-1 512 bit test vectors
+#[rustfmt::skip]
+pub const FNV1_512: &[(&[u8], U512)] = &[
+    (b"", U512::from_be_limbs([0xb86db0b1171f4416, 0xdca1e50f309990ac, 0xac87d059c9000000, 0x0000000000000d21, 0xe948f68a34c192f6, 0x2ea79bc942dbe7ce, 0x182036415f56e34b, 0xac982aac4afe9fd9])),
+    (b"a", U512::from_be_limbs([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bde])),
+    (b"b", U512::from_be_limbs([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bdd])),
+    (b"c", U512::from_be_limbs([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bdc])),
+    (b"d", U512::from_be_limbs([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bdb])),
+    (b"e", U512::from_be_limbs([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bda])),
+    (b"f", U512::from_be_limbs([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bd9])),
+    (b"fo", U512::from_be_limbs([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9571000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2bfd0])),
+    (b"foo", U512::from_be_limbs([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd81cfa37000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6ec85effdf])),
+    (b"foob", U512::from_be_limbs([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5457323f90000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16e7748d3ab])),
+    (b"fooba", U512::from_be_limbs([0x96b20c29347dfb41, 0xb5e3ebf2c34d267b, 0x6f4b9bfd9b000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x62609b01d2939a7c])),
+    (b"foobar", U512::from_be_limbs([0xb0ec738d9c6fd969, 0xd05f0b35f6c0effd, 0x2020946529000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xcf6faf7123c3fc56])),
+    (b"\x00", U512::from_be_limbs([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bbf])),
+    (b"a\x00", U512::from_be_limbs([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9576000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2c672])),
+    (b"b\x00", U512::from_be_limbs([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9575000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2c51b])),
+    (b"c\x00", U512::from_be_limbs([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9574000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2c3c4])),
+    (b"d\x00", U512::from_be_limbs([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9573000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2c26d])),
+    (b"e\x00", U512::from_be_limbs([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9572000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2c116])),
+    (b"f\x00", U512::from_be_limbs([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9571000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2bfbf])),
+    (b"fo\x00", U512::from_be_limbs([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd81cfa37000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6ec85effb0])),
+    (b"foo\x00", U512::from_be_limbs([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5457323f90000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16e7748d3c9])),
+    (b"foob\x00", U512::from_be_limbs([0x96b20c29347dfb41, 0xb5e3ebf2c34d267b, 0x6f4b9bfd9b000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x62609b01d2939a1d])),
+    (b"fooba\x00", U512::from_be_limbs([0xb0ec738d9c6fd969, 0xd05f0b35f6c0effd, 0x2020946529000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xcf6faf7123c3fc24])),
+    (b"foobar\x00", U512::from_be_limbs([0x82f6e10496de7834, 0xb08b21ef4650fbd5, 0x7cca978645000065, 0xcb74802739e0e571, 0x7522ecf6d1f9a52f, 0x5feefb4fab2273fd, 0xe8310f1b7b5c9a84, 0xeea41096eb97173a])),
+    (b"ch", U512::from_be_limbs([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e9574000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02d2c3ac])),
+    (b"cho", U512::from_be_limbs([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd81d0218000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6ec8642b1b])),
+    (b"chon", U512::from_be_limbs([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x545741f943000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16e7e35c143])),
+    (b"chong", U512::from_be_limbs([0x96b20c29347dfb41, 0xb5e3ebf2c34d267b, 0x6f679aba08000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x62609b0b1a05f0a2])),
+    (b"chongo", U512::from_be_limbs([0xb0ec738d9c6fd969, 0xd05f0b35f6c0effd, 0x4eea55315a000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xcf6fbbdfddf56961])),
+    (b"chongo was here!\n", U512::from_be_limbs([0xd7f6e5b0fc176c80, 0x04d9294174333e79, 0x83fa4be9aa9db1f8, 0x554a2d4e790edbb2, 0x979c5f87eab6ed7e, 0x9da2c344ddcc21e8, 0xf459937d4fefccbc, 0xa4bdee9343552565])),
+    (b"127.0.0.1", U512::from_be_limbs([0x4fdf00ecb9bc04dd, 0x193861aa2cbbcf01, 0xb0ba3ba1cab6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe87fda21, 0x48a61293c5f49c68])),
+    (b"\xff\x00\x00\x01", U512::from_be_limbs([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5453fa14d8000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16d0728bec1])),
+    (b"\x01\x00\x00\xff", U512::from_be_limbs([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x5456a0a8f2000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16e3638e34d])),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x00", U512::from_be_limbs([0xc8729f9a21fb3fa7, 0xa5e5d431bb6bf073, 0x4ef0a2d081008863, 0x9917b48e8c536b03, 0xf1cb7eb3557c5279, 0x8932b7be4b296932, 0x19bb3dd24913081a, 0xf3e5e4f78e840a19])),
+    (b"line 1\nline 2\nline 3", U512::from_be_limbs([0xf26bcb22a05cbb07, 0x9dc705a9b5167524, 0x24df4049e81988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x42455ef1586cb075, 0xc11ca244f150147e, 0x2cc297792603f397])),
+    (b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", U512::from_be_limbs([0xe29ec164bb0b00b4, 0xd45ef641cfc51ed8, 0x88d8d2133d181779, 0xb3a278a6520e84f2, 0xf6e1b86bfd30668c, 0x7552133713520fc0, 0x9c8530e2c4725a94, 0x39edf2816b2b8423])),
+    (b"chongo <Landon Curt Noll> /\\../\\", U512::from_be_limbs([0x7842c70b5b5570a0, 0xb3a16d5cdbbaf96b, 0xd2c2edece798aec6, 0x1d0f993918df6a0e, 0x6096f9cfc06b31ec, 0x684ff4ef93b20af9, 0xcc83e2cb43efd4f8, 0x0fae951dd1eee464])),
+];
+
+/// FNV-1 1024 bit test vectors
+#[rustfmt::skip]
+pub const FNV1_1024: &[(&[u8], U1024)] = &[
+    (b"", U1024::from_be_limbs([0x0000000000000000, 0x005f7a76758ecc4d, 0x32e56d5a591028b7, 0x4b29fc4223fdada1, 0x6c3bf34eda3674da, 0x9a21d90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000004c6d7, 0xeb6e73802734510a, 0x555f256cc005ae55, 0x6bde8cc9c6a93b21, 0xaff4b16c71ee90b3])),
+    (b"a", U1024::from_be_limbs([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef665f6])),
+    (b"b", U1024::from_be_limbs([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef665f5])),
+    (b"c", U1024::from_be_limbs([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef665f4])),
+    (b"d", U1024::from_be_limbs([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef665f3])),
+    (b"e", U1024::from_be_limbs([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef665f2])),
+    (b"f", U1024::from_be_limbs([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef665f1])),
+    (b"fo", U1024::from_be_limbs([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72c90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c16d2])),
+    (b"foo", U1024::from_be_limbs([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a841, 0x2718870000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d16f8f63c5])),
+    (b"foob", U1024::from_be_limbs([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be79, 0x306d200000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50beca015db8e3])),
+    (b"fooba", U1024::from_be_limbs([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c250528e2bf1, 0x76f3830000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc37df441e57b866])),
+    (b"foobar", U1024::from_be_limbs([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c3e596993, 0xcf5a8d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x82a53ca30e08f65c])),
+    (b"\x00", U1024::from_be_limbs([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef66597])),
+    (b"a\x00", U1024::from_be_limbs([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72ce0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c1e7e])),
+    (b"b\x00", U1024::from_be_limbs([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72cd0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c1cf1])),
+    (b"c\x00", U1024::from_be_limbs([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72cc0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c1b64])),
+    (b"d\x00", U1024::from_be_limbs([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72cb0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c19d7])),
+    (b"e\x00", U1024::from_be_limbs([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72ca0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c184a])),
+    (b"f\x00", U1024::from_be_limbs([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72c90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c16bd])),
+    (b"fo\x00", U1024::from_be_limbs([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a841, 0x2718870000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d16f8f63aa])),
+    (b"foo\x00", U1024::from_be_limbs([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be79, 0x306d200000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50beca015db881])),
+    (b"foob\x00", U1024::from_be_limbs([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c250528e2bf1, 0x76f3830000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc37df441e57b807])),
+    (b"fooba\x00", U1024::from_be_limbs([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c3e596993, 0xcf5a8d0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x82a53ca30e08f62e])),
+    (b"foobar\x00", U1024::from_be_limbs([0x0009dc921075fd8a, 0x5e3e1a372c72a59b, 0xb10cca1a94c8b238, 0x7d63a7efa7fca7a7, 0x17a64e5f55e55d46, 0x9863050000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000006708, 0xf44d008aaab08657, 0x4935502c49087c84, 0x9bcbbefa033f452a, 0xf6382426ba5d3bb2, 0x9a3f08dcc3e60cac])),
+    (b"ch", U1024::from_be_limbs([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfd72cc0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b541c1b0c])),
+    (b"cho", U1024::from_be_limbs([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a841, 0x2721680000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d16f95f1f3])),
+    (b"chon", U1024::from_be_limbs([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be79, 0x44c03b0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50beca0b8835b9])),
+    (b"chong", U1024::from_be_limbs([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c250528e2c1b, 0x2651380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc37df53e23b4f82])),
+    (b"chongo", U1024::from_be_limbs([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c3e59b9fc, 0xa7435a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x82a55515d5fa4cf5])),
+    (b"chongo was here!\n", U1024::from_be_limbs([0xfd40c54adb30b16f, 0xd3b5020075165bca, 0xa391c47ed5598b8c, 0xafc86f2bdf9fb3ef, 0xfcc0ff1f59aa8d9d, 0x1474620000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000002060c527cd, 0xafe7b3de88b930ff, 0x61bffb975575e8eb, 0x7caab48e688bb6b3, 0x552d707483b26fa3, 0x71639e736d38c8ab, 0xd0c2db0498dc32b1])),
+    (b"127.0.0.1", U1024::from_be_limbs([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4e3884143eab9754, 0x5737620000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701f66fa5, 0x2a1fbe886a2ef5fc])),
+    (b"\xff\x00\x00\x01", U1024::from_be_limbs([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be75, 0x53bf900000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec80239b989])),
+    (b"\x01\x00\x00\xff", U1024::from_be_limbs([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be76, 0x9fa0aa0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec8adc86bb1])),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x00", U1024::from_be_limbs([0x0fb21777d3faba3e, 0xd6d4fed9231afeba, 0x9951efd486fb5b9d, 0xb2d0999dbaf424da, 0x0a233c50347be1db, 0x1f81f10000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000009fc8e2, 0xdb69d70ab3c0555c, 0x87ad54ad422919a5, 0x9af729b7091e439f, 0xd510100f029593f1, 0x9d4ab94ff5e94b13])),
+    (b"line 1\nline 2\nline 3", U1024::from_be_limbs([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e82447f7a, 0x94e146d80a5443bb, 0xc398a791a54dc035, 0xdd0af10000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50b4d84301f29f06, 0x6a6f43dc95c1b8da, 0x28b61944192fb625])),
+    (b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", U1024::from_be_limbs([0xb4fa2cfea57383fb, 0x13773ec107cd1d83, 0x1e75607588fe3779, 0xe9251bdcc07ebc06, 0x1dc2e1ed13bf0bb1, 0x997acda7d40f1f6e, 0xa539674ebc053b87, 0x55a6cbc0f2c388f8, 0xde37c98d7d2ca154, 0x3c5e842ce5bba5e0, 0xbab61c06ad2ed8f4, 0xb4bdf2e7961f183c, 0x641aaab28a825907, 0xd908891848dd20d5, 0xc4c2ec6c1c3d9199, 0xaf0f3d9c037183e7])),
+    (b"chongo <Landon Curt Noll> /\\../\\", U1024::from_be_limbs([0x93ab73cfe16325c0, 0x2e323aae47c67155, 0x39db8de7ef5115ce, 0xc100c5a01529eb24, 0xe70f63a53bfd4c45, 0xa59bf00000000000, 0x0000000000000000, 0x0000005b41e7aa83, 0xaeb3a44e230384f5, 0xaca946916dadb061, 0x21c1b8482c0d1285, 0x542305ba8871386f, 0x0d1b9bd7c4b66802, 0xbb50de34d43ab560, 0xedbffa1d41cbdb48, 0xfd8d44056a31a040])),
+];
+
+/// FNV-1a 32 bit test vectors
+#[rustfmt::skip]
+pub const FNV1A_32: &[(&[u8], u32)] = &[
+    (b"", 0x811c9dc5),
+    (b"a", 0xe40c292c),
+    (b"b", 0xe70c2de5),
+    (b"c", 0xe60c2c52),
+    (b"d", 0xe10c2473),
+    (b"e", 0xe00c22e0),
+    (b"f", 0xe30c2799),
+    (b"fo", 0x6222e842),
+    (b"foo", 0xa9f37ed7),
+    (b"foob", 0x3f5076ef),
+    (b"fooba", 0x39aaa18a),
+    (b"foobar", 0xbf9cf968),
+    (b"\x00", 0x050c5d1f),
+    (b"a\x00", 0x2b24d044),
+    (b"b\x00", 0x9d2c3f7f),
+    (b"c\x00", 0x7729c516),
+    (b"d\x00", 0xb91d6109),
+    (b"e\x00", 0x931ae6a0),
+    (b"f\x00", 0x052255db),
+    (b"fo\x00", 0xbef39fe6),
+    (b"foo\x00", 0x6150ac75),
+    (b"foob\x00", 0x9aab3a3d),
+    (b"fooba\x00", 0x519c4c3e),
+    (b"foobar\x00", 0x0c1c9eb8),
+    (b"ch", 0x5f299f4e),
+    (b"cho", 0xef8580f3),
+    (b"chon", 0xac297727),
+    (b"chong", 0x4546b9c0),
+    (b"chongo", 0xbd564e7d),
+    (b"chongo was here!\n", 0xd49930d5),
+    (b"127.0.0.1", 0x08a3d11e),
+    (b"\xff\x00\x00\x01", 0xc48fb86d),
+    (b"\x01\x00\x00\xff", 0x2269f369),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x00", 0x9be17165),
+    (b"line 1\nline 2\nline 3", 0x97b4ea23),
+    (b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", 0xd19701c3),
+    (b"chongo <Landon Curt Noll> /\\../\\", 0x9a4e92e6),
+];
+
+/// FNV-1a 64 bit test vectors
+#[rustfmt::skip]
+pub const FNV1A_64: &[(&[u8], u64)] = &[
+    (b"", 0xcbf29ce484222325),
+    (b"a", 0xaf63dc4c8601ec8c),
+    (b"b", 0xaf63df4c8601f1a5),
+    (b"c", 0xaf63de4c8601eff2),
+    (b"d", 0xaf63d94c8601e773),
+    (b"e", 0xaf63d84c8601e5c0),
+    (b"f", 0xaf63db4c8601ead9),
+    (b"fo", 0x08985907b541d342),
+    (b"foo", 0xdcb27518fed9d577),
+    (b"foob", 0xdd120e790c2512af),
+    (b"fooba", 0xcac165afa2fef40a),
+    (b"foobar", 0x85944171f73967e8),
+    (b"\x00", 0xaf63bd4c8601b7df),
+    (b"a\x00", 0x089be207b544f1e4),
+    (b"b\x00", 0x08a61407b54d9b5f),
+    (b"c\x00", 0x08a2ae07b54ab836),
+    (b"d\x00", 0x0891b007b53c4869),
+    (b"e\x00", 0x088e4a07b5396540),
+    (b"f\x00", 0x08987c07b5420ebb),
+    (b"fo\x00", 0xdcb28a18fed9f926),
+    (b"foo\x00", 0xdd1270790c25b935),
+    (b"foob\x00", 0xcac146afa2febf5d),
+    (b"fooba\x00", 0x8593d371f738acfe),
+    (b"foobar\x00", 0x34531ca7168b8f38),
+    (b"ch", 0x08a25607b54a22ae),
+    (b"cho", 0xf5faf0190cf90df3),
+    (b"chon", 0xf27397910b3221c7),
+    (b"chong", 0x2c8c2b76062f22e0),
+    (b"chongo", 0xe150688c8217b8fd),
+    (b"chongo was here!\n", 0x46810940eff5f915),
+    (b"127.0.0.1", 0xaabafe7104d914be),
+    (b"\xff\x00\x00\x01", 0x6961196491cc682d),
+    (b"\x01\x00\x00\xff", 0xad2bb1774799dfe9),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x00", 0xa8c7f832281a39c5),
+    (b"line 1\nline 2\nline 3", 0x7829851fac17b143),
+    (b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", 0x8e87d7e7472b3883),
+    (b"chongo <Landon Curt Noll> /\\../\\", 0x2c8f4c9af81bcf06),
+];
+
+/// FNV-1a 128 bit test vectors
+#[rustfmt::skip]
+pub const FNV1A_128: &[(&[u8], u128)] = &[
+    (b"", 0x6c62272e07bb014262b821756295c58d),
+    (b"a", 0xd228cb696f1a8caf78912b704e4a8964),
+    (b"b", 0xd228cb69721a8caf78912b704e4a8d15),
+    (b"c", 0xd228cb69711a8caf78912b704e4a8bda),
+    (b"d", 0xd228cb696c1a8caf78912b704e4a85b3),
+    (b"e", 0xd228cb696b1a8caf78912b704e4a8478),
+    (b"f", 0xd228cb696e1a8caf78912b704e4a8829),
+    (b"fo", 0x08809542c0ab1be95aa0733055b5ae22),
+    (b"foo", 0xa68d5ed15f8b5822836dbc79768d78bf),
+    (b"foob", 0x696a39196d757277b806e974e013b7ef),
+    (b"fooba", 0x2a9456013d83d94f708142cfb842dbba),
+    (b"foobar", 0x343e1662793c64bf6f0d3597ba446f18),
+    (b"\x00", 0xd228cb69101a8caf78912b704e4a147f),
+    (b"a\x00", 0x0880954519ab1be95aa0733055b70e0c),
+    (b"b\x00", 0x0880954c7bab1be95aa0733055bb98d7),
+    (b"c\x00", 0x0880954a05ab1be95aa0733055ba153e),
+    (b"d\x00", 0x0880953db7ab1be95aa0733055b28341),
+    (b"e\x00", 0x0880953b41ab1be95aa0733055b0ffa8),
+    (b"f\x00", 0x08809542a3ab1be95aa0733055b58a73),
+    (b"fo\x00", 0xa68d5ed1348b5822836dbc79768d43d6),
+    (b"foo\x00", 0x696a39194f757277b806e974e0139305),
+    (b"foob\x00", 0x2a9456019e83d94f708142cfb8435315),
+    (b"fooba\x00", 0x343e16626b3c64bf6f0d3597ba445dde),
+    (b"foobar\x00", 0xe01fcf9a454ff78da540f1b23234b288),
+    (b"ch", 0x08809549ddab1be95aa0733055b9e406),
+    (b"cho", 0xa68d6bc82a8b5822836dbc797bbc0d33),
+    (b"chon", 0x697f5b59b6757277b806e97b4064716f),
+    (b"chong", 0x4af5cbd48a83d94f70814aa83b9714d8),
+    (b"chongo", 0xe4ad659b273c64bf6f16dd0152e67d2d),
+    (b"chongo was here!\n", 0xd09f538fec03781a034e1e32bab19a75),
+    (b"127.0.0.1", 0xa78a10ffe104e4586dcbbf0007dd9276),
+    (b"\xff\x00\x00\x01", 0x65e40463d3757277b806e85f49ba7a8d),
+    (b"\x01\x00\x00\xff", 0x66a5c4c6c1757277b806e89ae3a8a4f9),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x00", 0x9d30c1f78465995be47dda5e4e4e77ed),
+    (b"line 1\nline 2\nline 3", 0xcdb92c5b9d37edc377f61c7a33cc7feb),
+    (b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", 0x9212af7468bd20d05cf0581fd1840043),
+    (b"chongo <Landon Curt Noll> /\\../\\", 0x51392a3b0d394195533727930c41b74e),
+];
+
+/// FNV-1a 256 bit test vectors
+#[rustfmt::skip]
+pub const FNV1A_256: &[(&[u8], U256)] = &[
+    (b"", U256::from_be_limbs([0xdd268dbcaac55036, 0x2d98c384c4e576cc, 0xc8b1536847b6bbb3, 0x1023b4c8caee0535])),
+    (b"a", U256::from_be_limbs([0x63323fb0f35303ec, 0x28dc751d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811637c])),
+    (b"b", U256::from_be_limbs([0x63323fb0f35303ec, 0x28dc781d0a33bdfa, 0x4de6a99b7266494f, 0x6183b271681167a5])),
+    (b"c", U256::from_be_limbs([0x63323fb0f35303ec, 0x28dc771d0a33bdfa, 0x4de6a99b7266494f, 0x6183b27168116642])),
+    (b"d", U256::from_be_limbs([0x63323fb0f35303ec, 0x28dc721d0a33bdfa, 0x4de6a99b7266494f, 0x6183b27168115f53])),
+    (b"e", U256::from_be_limbs([0x63323fb0f35303ec, 0x28dc711d0a33bdfa, 0x4de6a99b7266494f, 0x6183b27168115df0])),
+    (b"f", U256::from_be_limbs([0x63323fb0f35303ec, 0x28dc741d0a33bdfa, 0x4de6a99b7266494f, 0x6183b27168116219])),
+    (b"fo", U256::from_be_limbs([0xf4f7a1c2efd0e1e4, 0xbb177a4525c0721a, 0x06dd328fa3d7a914, 0x39a07343501b89a2])),
+    (b"foo", U256::from_be_limbs([0x8b0e658c2f1c837f, 0x8d185ae359de3a17, 0x84bd1d30340f770b, 0xe97fd65816301747])),
+    (b"foob", U256::from_be_limbs([0xe46ddd4ed460b1f6, 0xd8dd2e459f2a8e9d, 0x123f79d831721584, 0xcc463c26c4b0184f])),
+    (b"fooba", U256::from_be_limbs([0x366f691cc852f013, 0x6acf588bb803c3d0, 0x4e05f6cc9133d727, 0x456569c2c03187ca])),
+    (b"foobar", U256::from_be_limbs([0xb055ea2f306cadad, 0x4f0f81c02d3889dc, 0x32453dad5ae35b75, 0x3ba1a91084af3428])),
+    (b"\x00", U256::from_be_limbs([0x63323fb0f35303ec, 0x28dc561d0a33bdfa, 0x4de6a99b7266494f, 0x6183b2716811387f])),
+    (b"a\x00", U256::from_be_limbs([0xf4f7a1c2efd0e1e4, 0xbb19e34525c0721a, 0x06dd328fa3d7a914, 0x39a07343501cf4f4])),
+    (b"b\x00", U256::from_be_limbs([0xf4f7a1c2efd0e1e4, 0xbb22354525c0721a, 0x06dd328fa3d7a914, 0x39a073435022b9cf])),
+    (b"c\x00", U256::from_be_limbs([0xf4f7a1c2efd0e1e4, 0xbb1f6f4525c0721a, 0x06dd328fa3d7a914, 0x39a073435020cd86])),
+    (b"d\x00", U256::from_be_limbs([0xf4f7a1c2efd0e1e4, 0xbb11914525c0721a, 0x06dd328fa3d7a914, 0x39a0734350173019])),
+    (b"e\x00", U256::from_be_limbs([0xf4f7a1c2efd0e1e4, 0xbb0ecb4525c0721a, 0x06dd328fa3d7a914, 0x39a07343501543d0])),
+    (b"f\x00", U256::from_be_limbs([0xf4f7a1c2efd0e1e4, 0xbb171d4525c0721a, 0x06dd328fa3d7a914, 0x39a07343501b08ab])),
+    (b"fo\x00", U256::from_be_limbs([0x8b0e658c2f1c837f, 0x8d182fe359de3a17, 0x84bd1d30340f770b, 0xe97fd658162fdba6])),
+    (b"foo\x00", U256::from_be_limbs([0xe46ddd4ed460b1f6, 0xd8dd50459f2a8e9d, 0x123f79d831721584, 0xcc463c26c4b04775])),
+    (b"foob\x00", U256::from_be_limbs([0x366f691cc852f013, 0x6acf798bb803c3d0, 0x4e05f6cc9133d727, 0x456569c2c031b58d])),
+    (b"fooba\x00", U256::from_be_limbs([0xb055ea2f306cadad, 0x4f0f93c02d3889dc, 0x32453dad5ae35b75, 0x3ba1a91084af4d1e])),
+    (b"foobar\x00", U256::from_be_limbs([0x6a7f34abc85de7d9, 0x51b5157eb5672c59, 0xb60487650947d391, 0xb12d71e7fef55378])),
+    (b"ch", U256::from_be_limbs([0xf4f7a1c2efd0e1e4, 0xbb1f574525c0721a, 0x06dd328fa3d7a914, 0x39a073435020ac3e])),
+    (b"cho", U256::from_be_limbs([0x8b0e658c2f1c837f, 0x9d2255e359de3a17, 0x84bd1d30340f770b, 0xe97fd6581d4ef453])),
+    (b"chon", U256::from_be_limbs([0xe46ddd4ed460b214, 0x359157459f2a8e9d, 0x123f79d831721584, 0xcc463c30a47cb097])),
+    (b"chong", U256::from_be_limbs([0x366f691cc85322aa, 0xc53cf58bb803c3d0, 0x4e05f6cc9133d727, 0x4565777418e95cd0])),
+    (b"chongo", U256::from_be_limbs([0xb055ea2f30c086e8, 0x6ce53fc02d3889dc, 0x32453dad5ae35b75, 0x3bb4a5fe8b9b9cdd])),
+    (b"chongo was here!\n", U256::from_be_limbs([0xac710b58eeee6731, 0x86c85cf976b784c1, 0x050e2ae114199970, 0x01ee6bb963c40f35])),
+    (b"127.0.0.1", U256::from_be_limbs([0x0c2033aa9025a7cf, 0xdabe36c403a80f65, 0x9b80c9a5b01178b5, 0xf98c65c649bd45ae])),
+    (b"\xff\x00\x00\x01", U256::from_be_limbs([0xe46ddd4ed460b6ea, 0x128c34459f2a8e9d, 0x123f79d831721584, 0xcc463dddd8c6734d])),
+    (b"\x01\x00\x00\xff", U256::from_be_limbs([0xe46ddd4ed460b0aa, 0x15e8f2459f2a8e9d, 0x123f79d831721584, 0xcc463bb329efd629])),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x00", U256::from_be_limbs([0xf63992974c8a9714, 0x7e5fd9b58e128067, 0x6847c11bde9a616a, 0xe0a6f17100e33055])),
+    (b"line 1\nline 2\nline 3", U256::from_be_limbs([0x5209c96840478a00, 0xddb95041850f0750, 0x6951a0bf96f4d369, 0x3031c6ca05fced73])),
+    (b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", U256::from_be_limbs([0x58cd768338c03b85, 0xb49fffbc9c933579, 0x10596052e9a51695, 0xf7bb992f4bc76183])),
+    (b"chongo <Landon Curt Noll> /\\../\\", U256::from_be_limbs([0x4e4a25bfb530772a, 0x8232c94adffde114, 0x56e83ac788b47f47, 0xa8cce602fda72b16])),
+];
+
+/// FNV-1a 512 bit test vectors
+#[rustfmt::skip]
+pub const FNV1A_512: &[(&[u8], U512)] = &[
+    (b"", U512::from_be_limbs([0xb86db0b1171f4416, 0xdca1e50f309990ac, 0xac87d059c9000000, 0x0000000000000d21, 0xe948f68a34c192f6, 0x2ea79bc942dbe7ce, 0x182036415f56e34b, 0xac982aac4afe9fd9])),
+    (b"a", U512::from_be_limbs([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec07000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b27ff88])),
+    (b"b", U512::from_be_limbs([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec0a000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b28038d])),
+    (b"c", U512::from_be_limbs([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec09000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b280236])),
+    (b"d", U512::from_be_limbs([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec0c000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b28063b])),
+    (b"e", U512::from_be_limbs([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec0b000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b2804e4])),
+    (b"f", U512::from_be_limbs([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec0e000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b2808e9])),
+    (b"fo", U512::from_be_limbs([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e4f48000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02a36b8a])),
+    (b"foo", U512::from_be_limbs([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd78fa55d000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6e88f58fd3])),
+    (b"foob", U512::from_be_limbs([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x535a6c1f4c000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d11981038627])),
+    (b"fooba", U512::from_be_limbs([0x96b20c29347dfb41, 0xb5e3ebf2c34d2679, 0xc7a7e1751a000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x6260292bdbb8e7ca])),
+    (b"foobar", U512::from_be_limbs([0xb0ec738d9c6fd969, 0xd05f0b35f6c0ed53, 0xadcacccd8e000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xced729c364be7788])),
+    (b"\x00", U512::from_be_limbs([0xe43a992dc8fc5ad7, 0xde493e3d696d6f85, 0xd64326ec28000000, 0x000000000011986f, 0x90c2532caf5be7d8, 0x8291baa894a39522, 0x5328b196bd6a8a64, 0x3fe12cd87b282bbf])),
+    (b"a\x00", U512::from_be_limbs([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e3ce9000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02975f38])),
+    (b"b\x00", U512::from_be_limbs([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e44f3000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d029cc1eb])),
+    (b"c\x00", U512::from_be_limbs([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e4245000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d029af65a])),
+    (b"d\x00", U512::from_be_limbs([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e4a4f000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02a0590d])),
+    (b"e\x00", U512::from_be_limbs([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e47a1000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d029e8d7c])),
+    (b"f\x00", U512::from_be_limbs([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e4fab000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d02a3f02f])),
+    (b"fo\x00", U512::from_be_limbs([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd78fa502000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6e88f515e6])),
+    (b"foo\x00", U512::from_be_limbs([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x535a6c1f6e000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d1198103b3b5])),
+    (b"foob\x00", U512::from_be_limbs([0x96b20c29347dfb41, 0xb5e3ebf2c34d2679, 0xc7a7e174fb000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x6260292bdbb8be41])),
+    (b"fooba\x00", U512::from_be_limbs([0xb0ec738d9c6fd969, 0xd05f0b35f6c0ed53, 0xadcacccda0000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xced729c364be8fa6])),
+    (b"foobar\x00", U512::from_be_limbs([0x82f6e10496de7834, 0xb08b21ef464cd247, 0x9e1d25e0ca000065, 0xcb74802739e0e571, 0x7522ecf6d1f9a52f, 0x5feefb4fab2273fd, 0xe8310f1b7b5c9a84, 0x2248f4cbfb322738])),
+    (b"ch", U512::from_be_limbs([0x7317dfed6c70dfec, 0x6adfced2a5e04d7e, 0xec744e426d000000, 0x0000000017933d7a, 0xf45d70def423a316, 0xf14117df272cd0fd, 0x6b85f0f7c9bf6c51, 0x96b3160d029b2bf2])),
+    (b"cho", U512::from_be_limbs([0x142433ed48a78bb4, 0x29a7dba8911e8824, 0xdcd7762ba8000000, 0x0000001f96475fbd, 0x69323ab91bbf83bd, 0x3e36fbfd7d0c038b, 0x1075dbff4f7a2150, 0xe9f28b6e7de76f5b])),
+    (b"chon", U512::from_be_limbs([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x532d3bed4d000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d10ab1160003])),
+    (b"chong", U512::from_be_limbs([0x96b20c29347dfb41, 0xb5e3ebf2c34d2679, 0x7c4c60f28f000000, 0x0038b4561715d5e5, 0xa4bd279918adecbc, 0xd2f439c85e285847, 0xa4345f1bfde8f24a, 0x62601553447a85fc])),
+    (b"chongo", U512::from_be_limbs([0xb0ec738d9c6fd969, 0xd05f0b35f6c0ecda, 0xdd9a5f832c000000, 0x4bf99f58ee4196af, 0xb9700e20110830fe, 0xa5396b76280e47fd, 0x022b6e81331ca1a9, 0xcebc9290c028f7f5])),
+    (b"chongo was here!\n", U512::from_be_limbs([0xd7f6e4d32796562a, 0xb427af61606a6dfd, 0xaf196d60149db1f8, 0x554a2d4e790edbb2, 0x979c5f87eab6ed7e, 0x9da2c344ddbaa66c, 0xe9c03c7e2c859263, 0x6fded5b8ee5f28b5])),
+    (b"127.0.0.1", U512::from_be_limbs([0x4fdf00ecb9bc04dd, 0x193861afb705bfec, 0xaea76e16abb6bd72, 0x1ec2eafe03c46248, 0xf7a6c247899280d6, 0xd2f42ff6b47bf220, 0x79dfd4bfe880ad93, 0x240076a3da31f232])),
+    (b"\xff\x00\x00\x01", U512::from_be_limbs([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x4d9c7b1112000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8cf2d5cbb353d])),
+    (b"\x01\x00\x00\xff", U512::from_be_limbs([0xf9fe9eefe38ca43f, 0xcf36c8fbc0d25bef, 0x544d070db8000000, 0x00002a5259a146c7, 0xf24cae042d99828e, 0x5baba0a28b18bf53, 0x0de9c3137ca2a369, 0x73f8d16aff9e42d1])),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x00", U512::from_be_limbs([0xc8729f9a21fb3fa7, 0xa5e5d431bb6bf073, 0x4ef0a2d081008863, 0x9917b48e8c536b03, 0xf1cb7eb3557c5279, 0x8932b7be4b296932, 0x19bb3dd24913081a, 0xf3e5e4f78e840a19])),
+    (b"line 1\nline 2\nline 3", U512::from_be_limbs([0x1d6d923f613d2ae4, 0xa8a41d3fc516ce0b, 0xae702981921988b4, 0xd6e4efc77313664f, 0x3729c4451f1a07bd, 0x42452e63f0082f9b, 0x089c608d3394904e, 0x05ef381c83fdf207])),
+    (b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", U512::from_be_limbs([0xc1cdafa8de101776, 0x8fb0e60181809526, 0x75db7b2d2fb62f5e, 0xd98dfda8f4287b73, 0x7975ba748f53bad6, 0x2a17bec6871d9263, 0x12f78ad5f4f52753, 0xf7e1c701673d9613])),
+    (b"chongo <Landon Curt Noll> /\\../\\", U512::from_be_limbs([0x51421b9f1c00632f, 0x71d4a349ebc2b9c5, 0xfa885f5ba098aec6, 0x1d0f993918d9eaac, 0x3bb6cc3496352252, 0xc7fd77a45bf1f229, 0xd520e7f7fac62f2b, 0x9a59287e37437bc2])),
+];
+
+/// FNV-1a 1024 bit test vectors
+#[rustfmt::skip]
+pub const FNV1A_1024: &[(&[u8], U1024)] = &[
+    (b"", U1024::from_be_limbs([0x0000000000000000, 0x005f7a76758ecc4d, 0x32e56d5a591028b7, 0x4b29fc4223fdada1, 0x6c3bf34eda3674da, 0x9a21d90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000000000004c6d7, 0xeb6e73802734510a, 0x555f256cc005ae55, 0x6bde8cc9c6a93b21, 0xaff4b16c71ee90b3])),
+    (b"a", U1024::from_be_limbs([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e570000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef695aa])),
+    (b"b", U1024::from_be_limbs([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e560000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef6941d])),
+    (b"c", U1024::from_be_limbs([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e550000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef69290])),
+    (b"d", U1024::from_be_limbs([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e5c0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef69d6b])),
+    (b"e", U1024::from_be_limbs([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e5b0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef69bde])),
+    (b"f", U1024::from_be_limbs([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e5a0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef69a51])),
+    (b"fo", U1024::from_be_limbs([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfddbd00000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b546d3226])),
+    (b"foo", U1024::from_be_limbs([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a842, 0x1b13d90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d1ed56fb35])),
+    (b"foob", U1024::from_be_limbs([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548c071, 0x54c2dc0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bf8d0fe3c5eb])),
+    (b"fooba", U1024::from_be_limbs([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c2505291fcd0, 0x55f4b60000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc390dc1a4395702])),
+    (b"foobar", U1024::from_be_limbs([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c4572d0b9, 0x85d5ae0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x847a554bacec98b0])),
+    (b"\x00", U1024::from_be_limbs([0x0000000000000000, 0x98d7c19fbce653df, 0x221b9f717d3490ff, 0x95ca87fdaef30d1b, 0x823372f85b24a372, 0xf50e380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000007685cd8, 0x1a491dbccc21ad06, 0x648d09a5c8cf5a78, 0x482054e91470b33d, 0xde77252caef66597])),
+    (b"a\x00", U1024::from_be_limbs([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfdd2950000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b546618a2])),
+    (b"b\x00", U1024::from_be_limbs([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfdcf7b0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b5463b0f9])),
+    (b"c\x00", U1024::from_be_limbs([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfdcc610000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b54614950])),
+    (b"d\x00", U1024::from_be_limbs([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfde2170000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b54721eef])),
+    (b"e\x00", U1024::from_be_limbs([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfddefd0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b546fb746])),
+    (b"f\x00", U1024::from_be_limbs([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfddbe30000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b546d4f9d])),
+    (b"fo\x00", U1024::from_be_limbs([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a842, 0x1b13b60000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d1ed56c4ee])),
+    (b"foo\x00", U1024::from_be_limbs([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548c071, 0x54c2ba0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bf8d0fe39131])),
+    (b"foob\x00", U1024::from_be_limbs([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c2505291fcd0, 0x55f5170000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc390dc1a439ed6f])),
+    (b"fooba\x00", U1024::from_be_limbs([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c4572d0b9, 0x85d5400000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x847a554bacebee1a])),
+    (b"foobar\x00", U1024::from_be_limbs([0x0009dc921075fd8a, 0x5e3e1a372c72a59b, 0xb10cca1a94c8b238, 0x7d63a7efa7fca7a7, 0x17a64e6c2d62fb61, 0x78f7860000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000006708, 0xf44d008aaab08657, 0x4935502c49087c84, 0x9bcbbefa033f452a, 0xf6382426ba5d3bb5, 0x71b6465b2ae8c8f0])),
+    (b"ch", U1024::from_be_limbs([0x00000000000000f4, 0x6ef41cd23a4dcdd4, 0x06834963b78e8224, 0x1a6f5cb06f403cbd, 0x5a7c8903cef6a5f4, 0xfdccc90000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000b7cd7fb20, 0xc3631dc8903952e9, 0xeeb7f618698f4c87, 0xda23ad74b2c5f6f1, 0xfec4a64b5461ea98])),
+    (b"cho", U1024::from_be_limbs([0x000000000001868c, 0xe88bd2c7cdc5fa5e, 0x52ebb9925ff5ea66, 0x8dff4576aa4ba658, 0x19176ce6b925a841, 0xf87eac0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x000011d09af071cf, 0x00b53007a8e594c7, 0x3348a3dbb339aead, 0x4953fdf93cfff548, 0x16f5e2d1dbd9610b])),
+    (b"chon", U1024::from_be_limbs([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548c02a, 0x35d2210000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bf71f01c09a1])),
+    (b"chong", U1024::from_be_limbs([0x00000003e27f563b, 0x2ca82d6f6b22a351, 0x17ddfb386bab86b4, 0xe52a63e0aa457ba1, 0xb5d6c25052917365, 0x92e6f30000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2ad7e6edea236c5a, 0xbdff1bce07f9c3b4, 0x5c98f798e3b69b8e, 0x2f946b142b391bbf, 0xdc38e3b15b7b280e])),
+    (b"chongo", U1024::from_be_limbs([0x00000631175fa7ae, 0x643ad08723d312c9, 0xfd024adb91f77f6b, 0x19587197a22bcdf2, 0x3727166c4473a5e0, 0x4b4f380000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000042, 0x70d11ef418ef08b8, 0xa49e1e825e547eb3, 0x9937f819222f3b7f, 0xc92a0e4707900888, 0x84391a0addfd9e6d])),
+    (b"chongo was here!\n", U1024::from_be_limbs([0xfd40c54adb30b16f, 0xd3b5020075165bca, 0xa391c47ed5598b8c, 0xb8354a81011d1f5c, 0x809a231c31cc8873, 0xc7dc840000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000002060c527cd, 0xafe7b3de88b930ff, 0x61bffb975575e8eb, 0x7caab48e688bb6b3, 0x552d7074847733a7, 0x26e137c1330aa0fd, 0x2822fd5b3a9fb541])),
+    (b"127.0.0.1", U1024::from_be_limbs([0xf6f747af25a9de26, 0xe8a493431e31b4a1, 0xed2a92304af6ca97, 0x6bc1d96ffcad3524, 0x4dfee3c8db86f5ec, 0x7deb570000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000f7ca87ce, 0x43227b98c144607e, 0x67cc50af99bcc5d1, 0x514bb0d923eededd, 0x69e8e74701ec81ae, 0xb6999b5958653d38])),
+    (b"\xff\x00\x00\x01", U1024::from_be_limbs([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548b876, 0x1819da0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bc74fa2c1899])),
+    (b"\x01\x00\x00\xff", U1024::from_be_limbs([0x00000000026f791f, 0x9147aedad1354bef, 0x7d238f3219005cbd, 0x6e8d664f6b4eefdb, 0xe94929e41548be67, 0xbbd31c0000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x001ba08046e07e04, 0x18fb7be0ec07b8ea, 0x87a61bb4f073e2ba, 0xb740db8398ef60cb, 0x9b50bec2e8e56ca1])),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x00", U1024::from_be_limbs([0x0fb21777d3faba3e, 0xd6d4fed9231afeba, 0x9951efd486fb5b9d, 0xb2d0999dbaf424da, 0x0a233c50347be1db, 0x1f81f10000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x00000000009fc8e2, 0xdb69d70ab3c0555c, 0x87ad54ad422919a5, 0x9af729b7091e439f, 0xd510100f029593f1, 0x9d4ab94ff5e94b13])),
+    (b"line 1\nline 2\nline 3", U1024::from_be_limbs([0xb897b5105c6e6e59, 0x7783b1cee11c5a1e, 0xfe09d71e827c26d6, 0x1d89e2177e10d83b, 0xb715c83d04f065f7, 0xfc7d930000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x78c10a52e7afdaf1, 0x67e608756cd3d314, 0x5361343de4d43872, 0xc6d1c526c07e9b42, 0x50b92896b957c79c, 0xe2c968f74ae97c46, 0x542a3e35be67f205])),
+    (b"http://www.isthe.com/chongo/tech/math/prime/mersenne.html#largest", U1024::from_be_limbs([0xce0387f34b25d448, 0xe7fb861ff18b6bd6, 0xa65bdae484b29fda, 0x4bd5b255c6bfa9d0, 0xd45f8098133db12d, 0xed4d65a7d40f1f6e, 0xa539674ebc053b87, 0x55ff14623ae2d766, 0x17486242ddc2e1f6, 0x7760bb7a07eb10fb, 0xf43f05d808f1c4fb, 0x8fd3e46d040426ed, 0x66b7082a84d2ab75, 0x7ad107ca87bf31ff, 0x8541d403dc73b26e, 0xfc56e6ac178c8847])),
+    (b"chongo <Landon Curt Noll> /\\../\\", U1024::from_be_limbs([0x93ab73cfe16325c0, 0x5ae5926f480a95d5, 0x5c5e7273418a3d15, 0x1a9d218d2dba627a, 0x9502c06ecb7b7046, 0xb061a10000000000, 0x0000000000000000, 0x0000005b41e7aa83, 0xaeb3a44e230384f5, 0xaca946916dadb061, 0x21c1b8482c0d1285, 0x542305ba8a9bcdfe, 0xb6ab252cd9b5e443, 0xa198f7c0c080b5ad, 0xc85a0ade03e8bcaa, 0x38eb139f3e6fe7c4])),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        fnv1_128, fnv1_32, fnv1_64, fnv1a, fnv1a_128, fnv1a_32, fnv1a_64, Fnv1, Fnv1a, FnvHasher,
+    };
+    use core::hash::Hasher;
+
+    #[test]
+    fn fnv() {
+        check_fnv(FNV1_32, FNV1A_32).unwrap();
+        check_fnv(FNV1_64, FNV1A_64).unwrap();
+        check_fnv(FNV1_128, FNV1A_128).unwrap();
+        check_fnv(FNV1_256, FNV1A_256).unwrap();
+        check_fnv(FNV1_512, FNV1A_512).unwrap();
+        check_fnv(FNV1_1024, FNV1A_1024).unwrap();
+    }
+
+    #[test]
+    fn const_fn() {
+        check(FNV1_32, fnv1_32).unwrap();
+        check(FNV1A_32, fnv1a_32).unwrap();
+        check(FNV1_64, fnv1_64).unwrap();
+        check(FNV1A_64, fnv1a_64).unwrap();
+        check(FNV1_128, fnv1_128).unwrap();
+        check(FNV1A_128, fnv1a_128).unwrap();
+    }
+
+    #[test]
+    fn hasher() {
+        check(FNV1_32, |data| {
+            let mut h = FnvHasher::<u32, Fnv1>::default();
+            h.write(data);
+            h.finish_full()
+        })
+        .unwrap();
+        check(FNV1A_512, |data| {
+            let mut h = FnvHasher::<U512, Fnv1a>::default();
+            h.write(data);
+            h.finish_full()
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn mismatch() {
+        let err = check(FNV1A_32, |data| {
+            fnv1a::<u32>(data) ^ (data == b"foo") as u32
+        });
+        assert_eq!(
+            err,
+            Err(Mismatch {
+                index: 8,
+                input: b"foo",
+                expected: 0xa9f37ed7,
+                actual: 0xa9f37ed6
+            })
+        );
+    }
+}
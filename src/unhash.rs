@@ -0,0 +1,210 @@
+//! Reversing FNV steps
+//!
+//! The FNV prime is odd and thus invertible modulo the state width.
+//! Every FNV-1 and FNV-1a step is a bijection on the state and can be undone
+//! given the byte that was hashed.
+
+use core::borrow::Borrow;
+use num_traits::AsPrimitive;
+
+use crate::{Custom, Fnv, Params, U1024, U256, U512};
+
+/// Remove bytes from the end of an FNV state
+///
+/// This allows backtracking in searches over hashed paths without re-hashing from the root.
+///
+/// ```
+/// use yafnv::{Fnv, Unhash};
+///
+/// let root = u64::OFFSET_BASIS.fnv1a(b"config/");
+/// let leaf = root.fnv1a(b"adc/rate");
+/// assert_eq!(leaf.unhash1a(b"adc/rate"), root);
+/// assert_eq!(leaf.unhash1a_byte(b'e'), root.fnv1a(b"adc/rat"));
+/// ```
+pub trait Unhash: Fnv
+where
+    u8: AsPrimitive<Self>,
+{
+    /// The multiplicative inverse of [`Fnv::PRIME`] modulo the state width
+    const PRIME_INVERSE: Self;
+
+    /// Undo an FNV-1a step with `byte`.
+    #[inline]
+    fn unhash1a_byte(self, byte: u8) -> Self {
+        self.wrapping_mul(&Self::PRIME_INVERSE) ^ byte.as_()
+    }
+
+    /// Undo FNV-1a steps with `data`.
+    ///
+    /// `data` is in the order it was hashed. It is removed from the end.
+    #[inline]
+    fn unhash1a<I>(self, data: I) -> Self
+    where
+        I: IntoIterator,
+        I::IntoIter: DoubleEndedIterator,
+        I::Item: Borrow<u8>,
+    {
+        data.into_iter()
+            .rev()
+            .fold(self, |hash, byte| hash.unhash1a_byte(*byte.borrow()))
+    }
+
+    /// Undo an FNV-1 (or FNV-0) step with `byte`.
+    #[inline]
+    fn unhash1_byte(self, byte: u8) -> Self {
+        (self ^ byte.as_()).wrapping_mul(&Self::PRIME_INVERSE)
+    }
+
+    /// Undo FNV-1 (or FNV-0) steps with `data`.
+    ///
+    /// `data` is in the order it was hashed. It is removed from the end.
+    #[inline]
+    fn unhash1<I>(self, data: I) -> Self
+    where
+        I: IntoIterator,
+        I::IntoIter: DoubleEndedIterator,
+        I::Item: Borrow<u8>,
+    {
+        data.into_iter()
+            .rev()
+            .fold(self, |hash, byte| hash.unhash1_byte(*byte.borrow()))
+    }
+}
+
+impl Unhash for u32 {
+    const PRIME_INVERSE: u32 = 0x359c449b;
+}
+impl Unhash for u64 {
+    const PRIME_INVERSE: u64 = 0xce965057aff6957b;
+}
+impl Unhash for u128 {
+    const PRIME_INVERSE: u128 = 0xb1041ad2562ff2ff2ff2ff2ff2ff2ff3;
+}
+
+impl Unhash for U256 {
+    const PRIME_INVERSE: U256 = U256::from_be_limbs([
+        0x2582c273cc7a1dc0,
+        0x44c5ed0a1884aff4,
+        0x7643c931c1fbac59,
+        0x6b72a8be60a1884b,
+    ]);
+}
+
+impl Unhash for U512 {
+    const PRIME_INVERSE: U512 = U512::from_be_limbs([
+        0x3f55f622598a79e6,
+        0xe05669715fcf53fa,
+        0x1b8469f82df94865,
+        0x811e99bfd03bb55d,
+        0x4b61c5c8c509b3df,
+        0x290cb023d337fa07,
+        0x76aba96c38b918a1,
+        0x367be52196047a67,
+    ]);
+}
+
+impl Unhash for U1024 {
+    const PRIME_INVERSE: U1024 = U1024::from_be_limbs([
+        0xb4533bf699450602,
+        0x18665aec6d071632,
+        0x18879eca5fe82486,
+        0xd235caec093b7c59,
+        0x755ca09d5563bf8a,
+        0xd82e5302944ff5ae,
+        0xc02944ff5aec0294,
+        0x4ff5aec02944ff5a,
+        0xec02944ff5aec029,
+        0x44ff5aec02944ff5,
+        0xaec02944ff5aec02,
+        0x944ff5aec02944ff,
+        0x5aec02944ff5aec0,
+        0x2944ff5aec02944f,
+        0xf5aec02944ff5aec,
+        0x02944ff5aec02945,
+    ]);
+}
+
+/// Multiplicative inverse of an odd `x` modulo `2^128`
+///
+/// The low bits are the inverse modulo smaller powers of two.
+pub(crate) const fn inverse(x: u128) -> u128 {
+    // Newton's iteration doubles the number of correct bits from 3
+    let mut y = x;
+    let mut i = 0;
+    while i < 6 {
+        y = y.wrapping_mul(2u128.wrapping_sub(x.wrapping_mul(y)));
+        i += 1;
+    }
+    y
+}
+
+macro_rules! custom_unhash {
+    ($($ty:ty),*) => {
+        $(
+            impl<P: 'static + Params<$ty>> Unhash for Custom<$ty, P> {
+                const PRIME_INVERSE: Self = Self::new({
+                    assert!(P::PRIME & 1 == 1, "prime not invertible");
+                    inverse(P::PRIME as u128) as $ty
+                });
+            }
+        )*
+    };
+}
+
+custom_unhash!(u32, u64, u128);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{fnv0, ConstParams};
+
+    fn roundtrip<T: Unhash + PartialEq + core::fmt::Debug>()
+    where
+        u8: AsPrimitive<T>,
+    {
+        let one: T = 1u8.as_();
+        assert_eq!(T::PRIME.wrapping_mul(&T::PRIME_INVERSE), one);
+        let root = T::OFFSET_BASIS.fnv1a(b"foo");
+        assert_eq!(root.fnv1a(b"bar").unhash1a(b"bar"), root);
+        assert_eq!(root.fnv1a(b"bar").unhash1a_byte(b'r'), root.fnv1a(b"ba"));
+        assert_eq!(root.fnv1(b"bar").unhash1(b"bar"), root);
+        assert_eq!(root.fnv1(b"bar").unhash1_byte(b'r'), root.fnv1(b"ba"));
+        assert_eq!(root.unhash1a(b""), root);
+    }
+
+    #[test]
+    fn widths() {
+        roundtrip::<u32>();
+        roundtrip::<u64>();
+        roundtrip::<u128>();
+        roundtrip::<U256>();
+        roundtrip::<U512>();
+        roundtrip::<U1024>();
+        roundtrip::<Custom<u32, ConstParams<0x01000193, 0x12345678>>>();
+        roundtrip::<Custom<u64, ConstParams<0x12345, 0>>>();
+        roundtrip::<Custom<u128, ConstParams<0x1_0000_0000_0000_0001, 1>>>();
+    }
+
+    #[test]
+    fn inverses() {
+        for x in [
+            1,
+            3,
+            0xff,
+            u32::PRIME as u128,
+            u64::PRIME as u128,
+            u128::MAX,
+        ] {
+            assert_eq!(inverse(x).wrapping_mul(x), 1);
+        }
+        assert_eq!(inverse(u32::PRIME as _) as u32, u32::PRIME_INVERSE);
+        assert_eq!(inverse(u64::PRIME as _) as u64, u64::PRIME_INVERSE);
+        assert_eq!(inverse(u128::PRIME), u128::PRIME_INVERSE);
+    }
+
+    #[test]
+    fn fnv0_root() {
+        assert_eq!(fnv0::<u32>(b"foobar").unhash1(b"foobar"), 0);
+        assert_eq!(fnv0::<u64>(b"foobar").unhash1(*b"bar"), fnv0(b"foo"));
+    }
+}
@@ -0,0 +1,331 @@
+//! Wide integer types for FNV-256, FNV-512, and FNV-1024
+//!
+//! These are minimal fixed-size unsigned integers that provide just enough arithmetic
+//! (wrapping multiplication and xor) to satisfy the [`Fnv`] trait bounds.
+
+use core::fmt;
+use core::ops::{BitXor, Mul};
+use num_traits::{AsPrimitive, WrappingMul};
+
+use crate::Fnv;
+
+/// Wrapping multiplication of little-endian `u64` limbs.
+///
+/// Zero limbs of `b` are skipped. This makes multiplication by the sparse FNV primes
+/// linear in the number of limbs.
+#[inline]
+fn wrapping_mul<const N: usize>(a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+    let mut r = [0; N];
+    for (j, &bj) in b.iter().enumerate() {
+        if bj == 0 {
+            continue;
+        }
+        let mut carry = 0;
+        for (ai, ri) in a.iter().zip(r[j..].iter_mut()) {
+            let t = *ri as u128 + *ai as u128 * bj as u128 + carry;
+            *ri = t as u64;
+            carry = t >> 64;
+        }
+    }
+    r
+}
+
+macro_rules! wide {
+    ($name:ident, $bits:literal, $limbs:literal, $bytes:literal) => {
+        #[doc = concat!("A ", $bits, " bit unsigned integer for FNV-", $bits, ".")]
+        ///
+        /// Only the arithmetic required for FNV is implemented.
+        /// Use the byte conversions to obtain the hash value.
+        /// Hexadecimal formatting is zero-padded to the full width.
+        #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $name([u64; $limbs]);
+
+        impl $name {
+            /// Zero
+            pub const ZERO: Self = Self([0; $limbs]);
+
+            /// Create from `u64` limbs, most significant limb first.
+            pub const fn from_be_limbs(limbs: [u64; $limbs]) -> Self {
+                let mut le = [0; $limbs];
+                let mut i = 0;
+                while i < $limbs {
+                    le[i] = limbs[$limbs - 1 - i];
+                    i += 1;
+                }
+                Self(le)
+            }
+
+            /// Return the `u64` limbs, most significant limb first.
+            pub const fn to_be_limbs(self) -> [u64; $limbs] {
+                Self::from_be_limbs(self.0).0
+            }
+
+            /// Create from big-endian bytes.
+            pub const fn from_be_bytes(bytes: [u8; $bytes]) -> Self {
+                let mut le = [0; $limbs];
+                let mut i = 0;
+                while i < $bytes {
+                    le[($bytes - 1 - i) / 8] |= (bytes[i] as u64) << ((($bytes - 1 - i) % 8) * 8);
+                    i += 1;
+                }
+                Self(le)
+            }
+
+            /// Return the big-endian bytes.
+            pub const fn to_be_bytes(self) -> [u8; $bytes] {
+                let mut bytes = [0; $bytes];
+                let mut i = 0;
+                while i < $bytes {
+                    bytes[i] = (self.0[($bytes - 1 - i) / 8] >> ((($bytes - 1 - i) % 8) * 8)) as u8;
+                    i += 1;
+                }
+                bytes
+            }
+
+            /// Create from little-endian bytes.
+            pub const fn from_le_bytes(bytes: [u8; $bytes]) -> Self {
+                let mut le = [0; $limbs];
+                let mut i = 0;
+                while i < $bytes {
+                    le[i / 8] |= (bytes[i] as u64) << ((i % 8) * 8);
+                    i += 1;
+                }
+                Self(le)
+            }
+
+            /// Return the little-endian bytes.
+            pub const fn to_le_bytes(self) -> [u8; $bytes] {
+                let mut bytes = [0; $bytes];
+                let mut i = 0;
+                while i < $bytes {
+                    bytes[i] = (self.0[i / 8] >> ((i % 8) * 8)) as u8;
+                    i += 1;
+                }
+                bytes
+            }
+        }
+
+        impl BitXor for $name {
+            type Output = Self;
+
+            #[inline]
+            fn bitxor(mut self, rhs: Self) -> Self {
+                for (a, b) in self.0.iter_mut().zip(rhs.0) {
+                    *a ^= b;
+                }
+                self
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+
+            /// Wrapping multiplication
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                Self(wrapping_mul(&self.0, &rhs.0))
+            }
+        }
+
+        impl WrappingMul for $name {
+            #[inline]
+            fn wrapping_mul(&self, v: &Self) -> Self {
+                *self * *v
+            }
+        }
+
+        impl AsPrimitive<$name> for u8 {
+            #[inline]
+            fn as_(self) -> $name {
+                let mut r = $name::ZERO;
+                r.0[0] = self as _;
+                r
+            }
+        }
+
+        impl AsPrimitive<u64> for $name {
+            /// Truncate to the least significant 64 bits
+            #[inline]
+            fn as_(self) -> u64 {
+                self.0[0]
+            }
+        }
+
+        impl fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                for b in self.to_be_bytes() {
+                    write!(f, "{:02x}", b)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl fmt::UpperHex for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                for b in self.to_be_bytes() {
+                    write!(f, "{:02X}", b)?;
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+wide!(U256, 256, 4, 32);
+wide!(U512, 512, 8, 64);
+wide!(U1024, 1024, 16, 128);
+
+impl Fnv for U256 {
+    const PRIME: U256 = U256::from_be_limbs([
+        0x0000000000000000,
+        0x0000010000000000,
+        0x0000000000000000,
+        0x0000000000000163,
+    ]);
+    const OFFSET_BASIS: U256 = U256::from_be_limbs([
+        0xdd268dbcaac55036,
+        0x2d98c384c4e576cc,
+        0xc8b1536847b6bbb3,
+        0x1023b4c8caee0535,
+    ]);
+}
+
+impl Fnv for U512 {
+    const PRIME: U512 = U512::from_be_limbs([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000001000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000157,
+    ]);
+    const OFFSET_BASIS: U512 = U512::from_be_limbs([
+        0xb86db0b1171f4416,
+        0xdca1e50f309990ac,
+        0xac87d059c9000000,
+        0x0000000000000d21,
+        0xe948f68a34c192f6,
+        0x2ea79bc942dbe7ce,
+        0x182036415f56e34b,
+        0xac982aac4afe9fd9,
+    ]);
+}
+
+impl Fnv for U1024 {
+    const PRIME: U1024 = U1024::from_be_limbs([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x000000000000018d,
+    ]);
+    const OFFSET_BASIS: U1024 = U1024::from_be_limbs([
+        0x0000000000000000,
+        0x005f7a76758ecc4d,
+        0x32e56d5a591028b7,
+        0x4b29fc4223fdada1,
+        0x6c3bf34eda3674da,
+        0x9a21d90000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x000000000004c6d7,
+        0xeb6e73802734510a,
+        0x555f256cc005ae55,
+        0x6bde8cc9c6a93b21,
+        0xaff4b16c71ee90b3,
+    ]);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fnv1a;
+
+    #[test]
+    fn mul() {
+        let a = U256::from_be_limbs([0, 0, 1, u64::MAX]);
+        let b = U256::from_be_limbs([0, 0, 0, 2]);
+        assert_eq!(a * b, U256::from_be_limbs([0, 0, 3, u64::MAX - 1]));
+        let c = U256::from_be_limbs([u64::MAX; 4]);
+        assert_eq!(c * c, U256::from_be_limbs([0, 0, 0, 1]));
+        assert_eq!(
+            u64::OFFSET_BASIS.wrapping_mul(u64::PRIME),
+            (U256::from_be_limbs([0, 0, 0, u64::OFFSET_BASIS])
+                * U256::from_be_limbs([0, 0, 0, u64::PRIME]))
+            .to_be_limbs()[3]
+        );
+    }
+
+    #[test]
+    fn bytes() {
+        let x = U256::OFFSET_BASIS;
+        assert_eq!(U256::from_be_bytes(x.to_be_bytes()), x);
+        assert_eq!(U256::from_le_bytes(x.to_le_bytes()), x);
+        assert_eq!(x.to_be_bytes()[..4], [0xdd, 0x26, 0x8d, 0xbc]);
+        assert_eq!(x.to_le_bytes()[..4], [0x35, 0x05, 0xee, 0xca]);
+    }
+
+    #[test]
+    fn vectors() {
+        assert_eq!(fnv1a::<U256>(b""), U256::OFFSET_BASIS);
+        assert_eq!(
+            fnv1a::<U256>(b"a"),
+            U256::from_be_limbs([
+                0x63323fb0f35303ec,
+                0x28dc751d0a33bdfa,
+                0x4de6a99b7266494f,
+                0x6183b2716811637c,
+            ])
+        );
+        assert_eq!(
+            fnv1a::<U512>(b"a"),
+            U512::from_be_limbs([
+                0xe43a992dc8fc5ad7,
+                0xde493e3d696d6f85,
+                0xd64326ec07000000,
+                0x000000000011986f,
+                0x90c2532caf5be7d8,
+                0x8291baa894a39522,
+                0x5328b196bd6a8a64,
+                0x3fe12cd87b27ff88,
+            ])
+        );
+        assert_eq!(
+            fnv1a::<U1024>(b"a"),
+            U1024::from_be_limbs([
+                0x0000000000000000,
+                0x98d7c19fbce653df,
+                0x221b9f717d3490ff,
+                0x95ca87fdaef30d1b,
+                0x823372f85b24a372,
+                0xf50e570000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000007685cd8,
+                0x1a491dbccc21ad06,
+                0x648d09a5c8cf5a78,
+                0x482054e91470b33d,
+                0xde77252caef695aa,
+            ])
+        );
+    }
+}